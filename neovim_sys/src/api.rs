@@ -2,5 +2,8 @@
 //! This module really only exists just to mimic neovim's file structre: `neovim/src/api/`
 //!
 pub mod buffer;
+pub mod keysets;
 pub mod nvim;
 pub mod private;
+pub mod tabpage;
+pub mod window;
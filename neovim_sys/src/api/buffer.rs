@@ -1,7 +1,12 @@
 //!
 //! This module contains function wrappers that are defined in `neovim/src/nvim/api/buffer.c`.
 //!
-use super::nvim::{LuaError, NvimString, Object};
+use super::{
+    keysets::{KeyDict_keymap, KeyDict_set_extmark},
+    nvim::{Array, Boolean, Dictionary, Integer, LuaError, NvimString, Object},
+};
+use crate::types::handle_T;
+use std::os::raw::c_int;
 
 extern "C" {
     /// Gets a buffer-scoped (b:) variable.
@@ -15,8 +20,196 @@ extern "C" {
     /// Gets a buffer option value.
     ///
     pub fn nvim_buf_get_option(buffer: Buffer, name: NvimString, err: *mut LuaError) -> Object;
+
+    /// Deletes the buffer. `opts` can contain `force` and `unload` `Boolean`s.
+    ///
+    pub fn nvim_buf_delete(buffer: Buffer, opts: Dictionary, err: *mut LuaError);
+
+    /// Gets the full file name for the buffer.
+    ///
+    pub fn nvim_buf_get_name(buffer: Buffer, err: *mut LuaError) -> NvimString;
+
+    /// Sets the full file name for the buffer.
+    ///
+    pub fn nvim_buf_set_name(buffer: Buffer, name: NvimString, err: *mut LuaError);
+
+    /// Checks if the buffer is valid and loaded.
+    ///
+    pub fn nvim_buf_is_loaded(buffer: Buffer) -> Boolean;
+
+    /// Checks if the buffer is valid (it may still be unloaded).
+    ///
+    pub fn nvim_buf_is_valid(buffer: Buffer) -> Boolean;
+
+    /// Like `nvim_get_keymap()`, but gets the buffer-local mappings.
+    ///
+    pub fn nvim_buf_get_keymap(
+        channel_id: u64,
+        buffer: Buffer,
+        mode: NvimString,
+        err: *mut LuaError,
+    ) -> Array;
+
+    /// Like `nvim_set_keymap()`, but for a buffer-local mapping.
+    ///
+    pub fn nvim_buf_set_keymap(
+        channel_id: u64,
+        buffer: Buffer,
+        mode: NvimString,
+        lhs: NvimString,
+        rhs: NvimString,
+        opts: *mut KeyDict_keymap,
+        err: *mut LuaError,
+    );
+
+    /// Deletes a buffer-local mapping for `mode`.
+    ///
+    pub fn nvim_buf_del_keymap(
+        channel_id: u64,
+        buffer: Buffer,
+        mode: NvimString,
+        lhs: NvimString,
+        err: *mut LuaError,
+    );
+
+    /// Gets the buffer's `b:changedtick`.
+    ///
+    pub fn nvim_buf_get_changedtick(buffer: Buffer, err: *mut LuaError) -> Integer;
+
+    /// Creates or updates an extmark. Positions are zero-indexed. Returns the extmark's ID.
+    ///
+    pub fn nvim_buf_set_extmark(
+        buffer: Buffer,
+        ns_id: Integer,
+        line: Integer,
+        col: Integer,
+        opts: *const KeyDict_set_extmark,
+        err: *mut LuaError,
+    ) -> Integer;
+
+    /// Gets extmarks in "traversal order" from a charwise region defined by `start` and `end`,
+    /// each of which can be an extmark ID or a `(row, col)` `Array`. `opts` can contain `limit`
+    /// (an `Integer`) and `details` (a `Boolean`).
+    ///
+    /// Returns an `Array` of `[id, row, col]` `Array`s (plus a `Dictionary` if `details` is set).
+    ///
+    pub fn nvim_buf_get_extmarks(
+        buffer: Buffer,
+        ns_id: Integer,
+        start: Object,
+        end: Object,
+        opts: Dictionary,
+        err: *mut LuaError,
+    ) -> Array;
+
+    /// Removes an extmark. Returns `true` if the extmark was found.
+    ///
+    pub fn nvim_buf_del_extmark(
+        buffer: Buffer,
+        ns_id: Integer,
+        id: Integer,
+        err: *mut LuaError,
+    ) -> Boolean;
+
+    /// Clears namespaced objects (highlights, extmarks, virtual text) from a line-range. Lines are
+    /// zero-indexed and `line_end` is exclusive; `-1` clears to the end of the buffer. An `ns_id`
+    /// of `-1` clears all namespaces.
+    ///
+    pub fn nvim_buf_clear_namespace(
+        buffer: Buffer,
+        ns_id: Integer,
+        line_start: Integer,
+        line_end: Integer,
+        err: *mut LuaError,
+    );
+
+    /// Gets the number of lines in the buffer.
+    ///
+    pub fn nvim_buf_line_count(buffer: Buffer, err: *mut LuaError) -> Integer;
+
+    /// Gets a line-range from the buffer. Indexing is zero-based, end-exclusive; negative indices
+    /// are interpreted as `length + 1 + index`. Returns an `Array` of `NvimString`s.
+    ///
+    /// `channel_id` should be one of the `*_INTERNAL_CALL` constants in `api::private`.
+    ///
+    pub fn nvim_buf_get_lines(
+        channel_id: u64,
+        buffer: Buffer,
+        start: Integer,
+        end: Integer,
+        strict_indexing: Boolean,
+        err: *mut LuaError,
+    ) -> Array;
+
+    /// Sets (replaces) a line-range in the buffer, using the same indexing as
+    /// `nvim_buf_get_lines()`. `replacement` should be an `Array` of `NvimString`s.
+    ///
+    pub fn nvim_buf_set_lines(
+        channel_id: u64,
+        buffer: Buffer,
+        start: Integer,
+        end: Integer,
+        strict_indexing: Boolean,
+        replacement: Array,
+        err: *mut LuaError,
+    );
+
+    /// Gets a range from the buffer. Indexing is zero-based; row indices are end-inclusive and
+    /// column indices are end-exclusive. `opts` is currently unused by nvim.
+    ///
+    /// Note that this was added in nvim 0.7.
+    ///
+    pub fn nvim_buf_get_text(
+        channel_id: u64,
+        buffer: Buffer,
+        start_row: Integer,
+        start_col: Integer,
+        end_row: Integer,
+        end_col: Integer,
+        opts: Dictionary,
+        err: *mut LuaError,
+    ) -> Array;
+
+    /// Sets (replaces) a range in the buffer, using the same indexing as `nvim_buf_get_text()`.
+    /// `replacement` should be an `Array` of `NvimString`s.
+    ///
+    pub fn nvim_buf_set_text(
+        channel_id: u64,
+        buffer: Buffer,
+        start_row: Integer,
+        start_col: Integer,
+        end_row: Integer,
+        end_col: Integer,
+        replacement: Array,
+        err: *mut LuaError,
+    );
 }
 
-/// The buffer number.
+/// The buffer handle (the buffer number).
 ///
-pub type Buffer = crate::types::handle_T;
+/// Like `Window` and `Tabpage`, it has the same layout as nvim's `handle_T`, but is its own type so
+/// that it can't be mixed up with other handles or plain integers.
+///
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Buffer(handle_T);
+
+impl Buffer {
+    /// nvim treats buffer `0` as "the current buffer" in all API calls; this is that buffer.
+    ///
+    pub const CURRENT: Self = Self(0);
+
+    /// Wraps the buffer handle `handle`.
+    ///
+    #[must_use]
+    pub const fn new(handle: c_int) -> Self {
+        Self(handle)
+    }
+
+    /// The buffer handle.
+    ///
+    #[must_use]
+    pub const fn handle(self) -> c_int {
+        self.0
+    }
+}
@@ -0,0 +1,76 @@
+//!
+//! This module contains the structs that nvim generates from `neovim/src/nvim/api/keysets.lua`.
+//!
+//! Some functions take one of these for their `opts` argument (shown as `Dict(name) *opts` in the C
+//! signatures and `{*opts}` in the docs) instead of a `Dictionary`. Each field is an `Object` that
+//! should be left as nil when unused, and fields must be in the same order as in `keysets.lua` for
+//! the nvim version being targeted.
+//!
+use super::nvim::Object;
+
+macro_rules! def_keyset {
+    ($(#[$meta:meta])* $name:ident { $($field:ident),+ $(,)? }) => {
+        $(#[$meta])*
+        #[allow(missing_docs)]
+        #[derive(Debug, Clone, PartialEq)]
+        #[repr(C)]
+        pub struct $name {
+            $(pub $field: Object),+
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: Object::new_nil()),+
+                }
+            }
+        }
+    };
+}
+
+def_keyset!(
+    /// Options for `nvim_buf_set_extmark()`.
+    ///
+    KeyDict_set_extmark {
+        id,
+        end_line,
+        end_row,
+        end_col,
+        hl_group,
+        virt_text,
+        virt_text_pos,
+        virt_text_win_col,
+        virt_text_hide,
+        hl_eol,
+        hl_mode,
+        ephemeral,
+        priority,
+        right_gravity,
+        end_right_gravity,
+        virt_lines,
+        virt_lines_above,
+        virt_lines_leftcol,
+        strict,
+        sign_text,
+        sign_hl_group,
+        number_hl_group,
+        line_hl_group,
+        cursorline_hl_group,
+    }
+);
+
+def_keyset!(
+    /// Options for `nvim_set_keymap()` and `nvim_buf_set_keymap()`. When nvim keeps `callback`,
+    /// it takes ownership of the `LuaRef` by setting the field's `luaref` to `LUA_NOREF`.
+    ///
+    KeyDict_keymap {
+        noremap,
+        nowait,
+        silent,
+        script,
+        expr,
+        unique,
+        callback,
+        desc,
+    }
+);
@@ -18,7 +18,7 @@ pub use self::{
     string::String as NvimString,
 };
 
-use super::buffer::Buffer;
+use super::{buffer::Buffer, keysets::KeyDict_keymap, tabpage::Tabpage, window::Window};
 
 /// Neovim defines a type `Boolean`, which is the same as a Rust `bool`.
 ///
@@ -32,9 +32,13 @@ pub type Integer = i64;
 ///
 pub type Float = f64;
 
-/// Neovim defines a type `LuaRef`, which is the same as a Rust `isize`.
+/// Neovim defines a type `LuaRef`, which is the same as a C `int`.
 ///
-pub type LuaRef = isize;
+pub type LuaRef = std::os::raw::c_int;
+
+/// The `LuaRef` that doesn't refer to anything (Lua's `LUA_NOREF`).
+///
+pub const LUA_NOREF: LuaRef = -2;
 
 extern "C" {
     /// Gets a global (g:) variable.
@@ -65,6 +69,31 @@ extern "C" {
     ///
     pub fn nvim_get_current_buf() -> Buffer;
 
+    /// Gets the current list of buffer handles, including unlisted (unloaded/deleted) buffers.
+    /// Returns an `Array` of `Buffer`s.
+    ///
+    pub fn nvim_list_bufs() -> Array;
+
+    /// Creates a new, empty, unnamed buffer. Returns `0` on error.
+    ///
+    pub fn nvim_create_buf(listed: Boolean, scratch: Boolean, err: *mut LuaError) -> Buffer;
+
+    /// Gets the current list of window handles. Returns an `Array` of `Window`s.
+    ///
+    pub fn nvim_list_wins() -> Array;
+
+    /// Gets the current window.
+    ///
+    pub fn nvim_get_current_win() -> Window;
+
+    /// Gets the current list of tabpage handles. Returns an `Array` of `Tabpage`s.
+    ///
+    pub fn nvim_list_tabpages() -> Array;
+
+    /// Gets the current tabpage.
+    ///
+    pub fn nvim_get_current_tabpage() -> Tabpage;
+
     /// Replaces terminal codes and keycodes in a string with the internal representation.
     ///
     pub fn nvim_replace_termcodes(
@@ -74,6 +103,10 @@ extern "C" {
         special: Boolean,
     ) -> NvimString;
 
+    /// Executes an Ex command.
+    ///
+    pub fn nvim_command(command: NvimString, err: *mut LuaError);
+
     /// Executes `Vimscript`.
     ///
     pub fn nvim_exec(src: NvimString, output: Boolean, err: *mut LuaError) -> NvimString;
@@ -87,6 +120,40 @@ extern "C" {
         err: *mut LuaError,
     );
 
+    /// Calls a `VimL` function.
+    ///
+    pub fn nvim_call_function(fn_name: NvimString, args: Array, err: *mut LuaError) -> Object;
+
+    /// Calls a `VimL` dictionary function.
+    ///
+    pub fn nvim_call_dict_function(
+        dict: Object,
+        fn_name: NvimString,
+        args: Array,
+        err: *mut LuaError,
+    ) -> Object;
+
+    /// Evaluates a `VimL` expression.
+    ///
+    pub fn nvim_eval(expr: NvimString, err: *mut LuaError) -> Object;
+
+    /// Executes Lua code. Arguments are available as `...` inside the chunk, and the chunk can
+    /// return a value.
+    ///
+    pub fn nvim_exec_lua(code: NvimString, args: Array, err: *mut LuaError) -> Object;
+
+    /// Gets a highlight definition by name.
+    ///
+    pub fn nvim_get_hl_by_name(name: NvimString, rgb: Boolean, err: *mut LuaError) -> Dictionary;
+
+    /// Gets a highlight definition by its ID.
+    ///
+    pub fn nvim_get_hl_by_id(hl_id: Integer, rgb: Boolean, err: *mut LuaError) -> Dictionary;
+
+    /// Gets a highlight group's ID by its name.
+    ///
+    pub fn nvim_get_hl_id_by_name(name: NvimString) -> Integer;
+
     /// Gets existing, non-anonymous namespaces.
     ///
     pub fn nvim_get_namespaces() -> Dictionary;
@@ -98,5 +165,23 @@ extern "C" {
     /// `mode` should be the 1-letter string of the mode ("n", "i", "v", etc).
     /// Returns an `Array` of `Dictionary`s.
     ///
-    pub fn nvim_get_keymap(mode: NvimString) -> Array;
+    /// `channel_id` should be one of the `*_INTERNAL_CALL` constants in `api::private`. With
+    /// `LUA_INTERNAL_CALL`, mappings with a callback include it as a new `LuaRef` that the caller
+    /// must release.
+    ///
+    pub fn nvim_get_keymap(channel_id: u64, mode: NvimString) -> Array;
+
+    /// Sets a global mapping for `mode` (a map-mode short-name, like "n", "!" or ""). `opts` can
+    /// have a `callback` (a `LuaRef`) instead of `rhs`, in which case `rhs` should be empty.
+    ///
+    /// `channel_id` should be one of the `*_INTERNAL_CALL` constants in `api::private`.
+    ///
+    pub fn nvim_set_keymap(
+        channel_id: u64,
+        mode: NvimString,
+        lhs: NvimString,
+        rhs: NvimString,
+        opts: *mut KeyDict_keymap,
+        err: *mut LuaError,
+    );
 }
@@ -2,6 +2,7 @@
 //! This module contains types and functions for working with neovim Lua `Object`s.
 //!
 use super::{Array, Boolean, Dictionary, Float, Integer, LuaRef, NvimString};
+use crate::api::{buffer::Buffer, private, tabpage::Tabpage, window::Window};
 use std::{
     borrow::Cow, convert::TryFrom, fmt::Debug, marker::PhantomData, mem::ManuallyDrop,
     num::NonZeroI64,
@@ -45,6 +46,11 @@ pub enum Error {
 /// - `string` (similar to Rust's `CString`; wrapped by this crate's `NvimString`)
 /// - `array` (wrapped by this crate's `Array`)
 /// - `dictionary` (wrapped by this crate's `Dictionary`)
+/// - `luaref` (a reference to a Lua value in the Lua registry, which the `Object` owns; see
+///   `Object::new_luaref()`)
+/// - `buffer` (a handle to a buffer; see `Buffer`)
+/// - `window` (a handle to a window; see `Window`)
+/// - `tabpage` (a handle to a tabpage; see `Tabpage`)
 ///
 #[repr(C)]
 pub struct Object {
@@ -72,6 +78,17 @@ macro_rules! new_clone_type {
     };
 }
 
+macro_rules! new_handle_type {
+    ($type_variant:ident, $handle:expr) => {
+        Self {
+            object_type: ObjectType::$type_variant,
+            data: ObjectData {
+                integer: Integer::from($handle),
+            },
+        }
+    };
+}
+
 macro_rules! try_as_type {
     ($_self:expr, $object_type_variant:ident, $field_name:ident) => {
         match $_self.object_type {
@@ -126,6 +143,44 @@ impl Object {
         }
     }
 
+    /// Constructor for an `Object` that wraps a `LuaRef`. The `Object` takes ownership of the
+    /// reference, like nvim's `Object`s do: dropping it releases the reference from the Lua
+    /// registry, and cloning it creates a new reference (see `api_free_luaref()` and
+    /// `api_new_luaref()`). `try_into_luaref()` takes the reference back out.
+    ///
+    #[must_use]
+    #[inline]
+    pub const fn new_luaref(luaref: LuaRef) -> Self {
+        Self {
+            object_type: ObjectType::kObjectTypeLuaRef,
+            data: ObjectData { luaref },
+        }
+    }
+
+    /// Constructor for an `Object` that wraps a `Buffer` handle.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn new_buffer(buffer: Buffer) -> Self {
+        new_handle_type!(kObjectTypeBuffer, buffer.handle())
+    }
+
+    /// Constructor for an `Object` that wraps a `Window` handle.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn new_window(window: Window) -> Self {
+        new_handle_type!(kObjectTypeWindow, window.handle())
+    }
+
+    /// Constructor for an `Object` that wraps a `Tabpage` handle.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn new_tabpage(tabpage: Tabpage) -> Self {
+        new_handle_type!(kObjectTypeTabpage, tabpage.handle())
+    }
+
     /// Accessor to the internal `ObjectType`.
     ///
     #[must_use]
@@ -223,6 +278,46 @@ impl Object {
         try_as_ref_type!(self, kObjectTypeDictionary, dictionary)
     }
 
+    /// Tries to extract the inner `LuaRef`.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `LuaRef`.
+    ///
+    pub const fn try_as_luaref(&self) -> Result<LuaRef, Error> {
+        try_as_type!(self, kObjectTypeLuaRef, luaref)
+    }
+
+    /// Tries to extract the inner `Buffer` handle.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `Buffer`.
+    ///
+    pub const fn try_as_buffer(&self) -> Result<Buffer, Error> {
+        try_as_type!(self, kObjectTypeBuffer, buffer)
+    }
+
+    /// Tries to extract the inner `Window` handle.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `Window`.
+    ///
+    pub const fn try_as_window(&self) -> Result<Window, Error> {
+        try_as_type!(self, kObjectTypeWindow, window)
+    }
+
+    /// Tries to extract the inner `Tabpage` handle.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `Tabpage`.
+    ///
+    pub const fn try_as_tabpage(&self) -> Result<Tabpage, Error> {
+        try_as_type!(self, kObjectTypeTabpage, tabpage)
+    }
+
     /// Counterpart to `try_as_boolean()`, but does not check `self`'s `object_type`, thus calling
     /// this if `self`'s internal data represents another type will give unexpected results.
     ///
@@ -277,6 +372,42 @@ impl Object {
         self.data.dictionary()
     }
 
+    /// Counterpart to `try_as_luaref()`, but does not check `self`'s `object_type`, thus calling
+    /// this if `self`'s internal data represents another type will give unexpected results.
+    ///
+    #[must_use]
+    #[inline]
+    pub const fn as_luaref_unchecked(&self) -> LuaRef {
+        self.data.luaref()
+    }
+
+    /// Counterpart to `try_as_buffer()`, but does not check `self`'s `object_type`, thus calling
+    /// this if `self`'s internal data represents another type will give unexpected results.
+    ///
+    #[must_use]
+    #[inline]
+    pub const fn as_buffer_unchecked(&self) -> Buffer {
+        self.data.buffer()
+    }
+
+    /// Counterpart to `try_as_window()`, but does not check `self`'s `object_type`, thus calling
+    /// this if `self`'s internal data represents another type will give unexpected results.
+    ///
+    #[must_use]
+    #[inline]
+    pub const fn as_window_unchecked(&self) -> Window {
+        self.data.window()
+    }
+
+    /// Counterpart to `try_as_tabpage()`, but does not check `self`'s `object_type`, thus calling
+    /// this if `self`'s internal data represents another type will give unexpected results.
+    ///
+    #[must_use]
+    #[inline]
+    pub const fn as_tabpage_unchecked(&self) -> Tabpage {
+        self.data.tabpage()
+    }
+
     /// Owned/consuming version of `try_as_boolean()`.
     ///
     /// # Errors
@@ -343,6 +474,52 @@ impl Object {
         try_into_ref_type!(self, kObjectTypeDictionary, into_dictionary_unchecked)
     }
 
+    /// Owned/consuming version of `try_as_luaref()`.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `LuaRef`.
+    ///
+    #[inline]
+    pub fn try_into_luaref(self) -> Result<LuaRef, Error> {
+        let luaref = try_into_type!(self, kObjectTypeLuaRef, luaref)?;
+        std::mem::forget(self);
+        Ok(luaref)
+    }
+
+    /// Owned/consuming version of `try_as_buffer()`.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `Buffer`.
+    ///
+    #[inline]
+    pub fn try_into_buffer(self) -> Result<Buffer, Error> {
+        try_into_type!(self, kObjectTypeBuffer, buffer)
+    }
+
+    /// Owned/consuming version of `try_as_window()`.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `Window`.
+    ///
+    #[inline]
+    pub fn try_into_window(self) -> Result<Window, Error> {
+        try_into_type!(self, kObjectTypeWindow, window)
+    }
+
+    /// Owned/consuming version of `try_as_tabpage()`.
+    ///
+    /// # Errors
+    ///
+    /// If the wrapped type is not a `Tabpage`.
+    ///
+    #[inline]
+    pub fn try_into_tabpage(self) -> Result<Tabpage, Error> {
+        try_into_type!(self, kObjectTypeTabpage, tabpage)
+    }
+
     /// Similar to `as_boolean_unchecked()`, where it does not check `self`'s `object_type` (thus
     /// calling this if `self`'s internal data represents another type will give unexpected
     /// results), but instead of taking a reference to `self`, this consumes `self` and returns the
@@ -437,6 +614,52 @@ impl Object {
         d
     }
 
+    /// Similar to `as_luaref_unchecked()`, where it does not check `self`'s `object_type` (thus
+    /// calling this if `self`'s internal data represents another type will give unexpected
+    /// results), but instead of taking a reference to `self`, this consumes `self` and returns the
+    /// value as a `LuaRef`. The caller takes over ownership of the reference.
+    ///
+    #[inline]
+    #[must_use]
+    pub const fn into_luaref_unchecked(self) -> LuaRef {
+        let luaref = self.data.luaref();
+        std::mem::forget(self);
+        luaref
+    }
+
+    /// Similar to `as_buffer_unchecked()`, where it does not check `self`'s `object_type` (thus
+    /// calling this if `self`'s internal data represents another type will give unexpected
+    /// results), but instead of taking a reference to `self`, this consumes `self` and returns the
+    /// value as a `Buffer`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn into_buffer_unchecked(self) -> Buffer {
+        self.data.buffer()
+    }
+
+    /// Similar to `as_window_unchecked()`, where it does not check `self`'s `object_type` (thus
+    /// calling this if `self`'s internal data represents another type will give unexpected
+    /// results), but instead of taking a reference to `self`, this consumes `self` and returns the
+    /// value as a `Window`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn into_window_unchecked(self) -> Window {
+        self.data.window()
+    }
+
+    /// Similar to `as_tabpage_unchecked()`, where it does not check `self`'s `object_type` (thus
+    /// calling this if `self`'s internal data represents another type will give unexpected
+    /// results), but instead of taking a reference to `self`, this consumes `self` and returns the
+    /// value as a `Tabpage`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn into_tabpage_unchecked(self) -> Tabpage {
+        self.data.tabpage()
+    }
+
     /// Convenience method for checking if `self` has `ObjectType::kObjectTypeNil`.
     ///
     #[inline]
@@ -484,6 +707,38 @@ impl Object {
     pub fn is_dictionary(&self) -> bool {
         self.object_type == ObjectType::kObjectTypeDictionary
     }
+
+    /// Convenience method for checking if `self` has `ObjectType::kObjectTypeLuaRef`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn is_luaref(&self) -> bool {
+        self.object_type == ObjectType::kObjectTypeLuaRef
+    }
+
+    /// Convenience method for checking if `self` has `ObjectType::kObjectTypeBuffer`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn is_buffer(&self) -> bool {
+        self.object_type == ObjectType::kObjectTypeBuffer
+    }
+
+    /// Convenience method for checking if `self` has `ObjectType::kObjectTypeWindow`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn is_window(&self) -> bool {
+        self.object_type == ObjectType::kObjectTypeWindow
+    }
+
+    /// Convenience method for checking if `self` has `ObjectType::kObjectTypeTabpage`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn is_tabpage(&self) -> bool {
+        self.object_type == ObjectType::kObjectTypeTabpage
+    }
 }
 
 impl Default for Object {
@@ -602,11 +857,18 @@ impl Clone for Object {
         match self.object_type {
             ObjectType::kObjectTypeNil => Self::new_nil(),
             ObjectType::kObjectTypeBoolean => copy_inner_for_clone!(self, boolean),
-            ObjectType::kObjectTypeInteger => copy_inner_for_clone!(self, integer),
+            // Buffer, Window, and Tabpage handles are stored in the `integer` field.
+            ObjectType::kObjectTypeInteger
+            | ObjectType::kObjectTypeBuffer
+            | ObjectType::kObjectTypeWindow
+            | ObjectType::kObjectTypeTabpage => copy_inner_for_clone!(self, integer),
             ObjectType::kObjectTypeFloat => copy_inner_for_clone!(self, float),
             ObjectType::kObjectTypeString => clone_inner_for_clone!(self, string),
             ObjectType::kObjectTypeArray => clone_inner_for_clone!(self, array),
             ObjectType::kObjectTypeDictionary => clone_inner_for_clone!(self, dictionary),
+            ObjectType::kObjectTypeLuaRef => {
+                Self::new_luaref(unsafe { private::api_new_luaref(self.data.luaref()) })
+            }
         }
     }
 }
@@ -617,7 +879,13 @@ impl Drop for Object {
             ObjectType::kObjectTypeNil
             | ObjectType::kObjectTypeBoolean
             | ObjectType::kObjectTypeInteger
-            | ObjectType::kObjectTypeFloat => (),
+            | ObjectType::kObjectTypeFloat
+            | ObjectType::kObjectTypeBuffer
+            | ObjectType::kObjectTypeWindow
+            | ObjectType::kObjectTypeTabpage => (),
+            ObjectType::kObjectTypeLuaRef => unsafe {
+                private::api_free_luaref(self.data.luaref());
+            },
             ObjectType::kObjectTypeString => {
                 let data = &mut self.data;
                 unsafe { ManuallyDrop::drop(&mut data.string) };
@@ -647,6 +915,10 @@ impl Debug for Object {
             ObjectType::kObjectTypeString => d.field("data", &self.data.string().to_string_lossy()),
             ObjectType::kObjectTypeArray => d.field("data", self.data.array()),
             ObjectType::kObjectTypeDictionary => d.field("data", self.data.dictionary()),
+            ObjectType::kObjectTypeLuaRef => d.field("data", &self.data.luaref()),
+            ObjectType::kObjectTypeBuffer
+            | ObjectType::kObjectTypeWindow
+            | ObjectType::kObjectTypeTabpage => d.field("data", &self.data.handle()),
         };
 
         d.finish()
@@ -735,6 +1007,31 @@ impl TryFrom<Object> for String {
     }
 }
 
+impl TryFrom<Object> for Dictionary {
+    type Error = Error;
+
+    fn try_from(value: Object) -> Result<Self, Self::Error> {
+        value.try_into_dictionary()
+    }
+}
+
+impl<T> TryFrom<Object> for Vec<T>
+where
+    T: TryFrom<Object, Error = Error>,
+{
+    type Error = Error;
+
+    /// Converts an `Array` `Object` by converting each of its items to `T`.
+    ///
+    fn try_from(value: Object) -> Result<Self, Self::Error> {
+        value
+            .try_into_array()?
+            .into_iter()
+            .map(T::try_from)
+            .collect()
+    }
+}
+
 impl PartialEq for Object {
     fn eq(&self, other: &Self) -> bool {
         if self.object_type != other.object_type {
@@ -749,6 +1046,10 @@ impl PartialEq for Object {
             ObjectType::kObjectTypeString => self.data.string() == other.data.string(),
             ObjectType::kObjectTypeArray => self.data.array() == other.data.array(),
             ObjectType::kObjectTypeDictionary => self.data.dictionary() == other.data.dictionary(),
+            ObjectType::kObjectTypeLuaRef => self.data.luaref() == other.data.luaref(),
+            ObjectType::kObjectTypeBuffer
+            | ObjectType::kObjectTypeWindow
+            | ObjectType::kObjectTypeTabpage => self.data.handle() == other.data.handle(),
         }
     }
 }
@@ -785,11 +1086,24 @@ pub enum ObjectType {
 
     /// Dictionary
     kObjectTypeDictionary,
-    // kObjectTypeLuaRef,
+
+    /// A reference to a Lua value (usually a function) in the Lua registry.
+    ///
+    kObjectTypeLuaRef,
+
     // EXT types, cannot be split or reordered, see #EXT_OBJECT_TYPE_SHIFT
-    // kObjectTypeBuffer,
-    // kObjectTypeWindow,
-    // kObjectTypeTabpage,
+    //
+    /// Buffer handle; stored in the `integer` field.
+    ///
+    kObjectTypeBuffer,
+
+    /// Window handle; stored in the `integer` field.
+    ///
+    kObjectTypeWindow,
+
+    /// Tabpage handle; stored in the `integer` field.
+    ///
+    kObjectTypeTabpage,
 }
 
 /// Holds the data for an `Object`.
@@ -830,6 +1144,29 @@ impl ObjectData {
     pub(crate) fn dictionary(&self) -> &Dictionary {
         unsafe { &self.dictionary }
     }
+
+    pub(crate) const fn luaref(&self) -> LuaRef {
+        unsafe { self.luaref }
+    }
+
+    // nvim stores `Buffer`, `Window`, and `Tabpage` handles (which are `int`s) in the `integer`
+    // field, so the cast can't truncate anything nvim gave us.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn handle(&self) -> crate::types::handle_T {
+        unsafe { self.integer as crate::types::handle_T }
+    }
+
+    pub(crate) const fn buffer(&self) -> Buffer {
+        Buffer::new(self.handle())
+    }
+
+    pub(crate) const fn window(&self) -> Window {
+        Window::new(self.handle())
+    }
+
+    pub(crate) const fn tabpage(&self) -> Tabpage {
+        Tabpage::new(self.handle())
+    }
 }
 
 #[cfg(test)]
@@ -960,6 +1297,68 @@ mod tests {
                 assert!(object.try_as_dictionary().is_err());
             }
         }
+        #[test]
+        fn test_try_as_luaref() {
+            // luaref
+            {
+                let object = Object::new_luaref(42);
+                assert_eq!(object.try_as_luaref().unwrap(), 42);
+            }
+
+            // Not luaref
+            {
+                let object = Object::from(42);
+                assert!(object.try_as_luaref().is_err());
+            }
+        }
+
+        #[test]
+        fn test_try_as_buffer() {
+            // buffer
+            {
+                let object = Object::new_buffer(Buffer::new(3));
+                assert_eq!(object.try_as_buffer().unwrap(), Buffer::new(3));
+            }
+
+            // Not buffer
+            {
+                let object = Object::from(3);
+                assert!(object.try_as_buffer().is_err());
+
+                let object = Object::new_window(Window::new(3));
+                assert!(object.try_as_buffer().is_err());
+            }
+        }
+
+        #[test]
+        fn test_try_as_window() {
+            // window
+            {
+                let object = Object::new_window(Window::new(1000));
+                assert_eq!(object.try_as_window().unwrap(), Window::new(1000));
+            }
+
+            // Not window
+            {
+                let object = Object::new_tabpage(Tabpage::new(1000));
+                assert!(object.try_as_window().is_err());
+            }
+        }
+
+        #[test]
+        fn test_try_as_tabpage() {
+            // tabpage
+            {
+                let object = Object::new_tabpage(Tabpage::new(1));
+                assert_eq!(object.try_as_tabpage().unwrap(), Tabpage::new(1));
+            }
+
+            // Not tabpage
+            {
+                let object = Object::new_buffer(Buffer::new(1));
+                assert!(object.try_as_tabpage().is_err());
+            }
+        }
     }
 
     mod as_unchecked {
@@ -1020,6 +1419,34 @@ mod tests {
         use super::*;
         use crate::api::nvim::KeyValuePair;
 
+        #[test]
+        fn test_try_from_for_vec() {
+            let object = Object::from(Array::new_from([Object::from(1), Object::from(2)]));
+            assert_eq!(Vec::<Integer>::try_from(object).unwrap(), vec![1, 2]);
+
+            let object = Object::from(Array::new_from([
+                Object::from(NvimString::new_unchecked("one")),
+                Object::from(NvimString::new_unchecked("two")),
+            ]));
+            assert_eq!(
+                Vec::<String>::try_from(object).unwrap(),
+                vec!["one".to_string(), "two".to_string()]
+            );
+
+            let object = Object::from(Array::new_from([
+                Object::from(Array::new_from([Object::from(1)])),
+                Object::from(Array::new()),
+            ]));
+            assert_eq!(
+                Vec::<Vec<u8>>::try_from(object).unwrap(),
+                vec![vec![1], vec![]]
+            );
+
+            let object = Object::from(Array::new_from([Object::from(1), Object::from(true)]));
+            assert!(Vec::<Integer>::try_from(object).is_err());
+            assert!(Vec::<Integer>::try_from(Object::from(1)).is_err());
+        }
+
         #[test]
         fn test_try_into_boolean() {
             // boolean
@@ -1124,6 +1551,34 @@ mod tests {
                 assert!(object.try_into_dictionary().is_err());
             }
         }
+        #[test]
+        fn test_try_into_handles() {
+            let object = Object::new_luaref(7);
+            assert_eq!(object.try_into_luaref().unwrap(), 7);
+
+            let object = Object::new_buffer(Buffer::new(7));
+            assert_eq!(object.try_into_buffer().unwrap(), Buffer::new(7));
+
+            let object = Object::new_window(Window::new(1001));
+            assert_eq!(object.try_into_window().unwrap(), Window::new(1001));
+
+            let object = Object::new_tabpage(Tabpage::new(2));
+            assert_eq!(object.try_into_tabpage().unwrap(), Tabpage::new(2));
+
+            let object = Object::new_buffer(Buffer::new(7));
+            assert!(object.try_into_window().is_err());
+        }
+
+        #[test]
+        fn test_handles_clone_and_eq() {
+            let buffer = Object::new_buffer(Buffer::new(7));
+            assert_eq!(buffer.clone(), buffer);
+            assert_ne!(buffer, Object::new_window(Window::new(7)));
+            assert_ne!(buffer, Object::from(7));
+
+            let luaref = Object::new_luaref(7);
+            assert_eq!(luaref.clone(), luaref);
+        }
     }
 
     mod into_unchecked {
@@ -3,11 +3,23 @@
 //!
 use super::buffer::Buffer;
 use crate::{
-    api::nvim::{LuaError, NvimString, Object},
+    api::nvim::{LuaError, LuaRef, NvimString, Object},
     buffer_defs::buf_T,
 };
 use std::{ffi::c_void, os::raw::c_int};
 
+/// Mask for all internal calls; from `nvim/api/private/defs.h`.
+///
+pub const INTERNAL_CALL_MASK: u64 = 1 << (u64::BITS - 1);
+
+/// Internal call from `VimL` code; use this as a `channel_id` for functions that take one.
+///
+pub const VIML_INTERNAL_CALL: u64 = INTERNAL_CALL_MASK;
+
+/// Internal call from Lua code; use this as a `channel_id` for functions that take one.
+///
+pub const LUA_INTERNAL_CALL: u64 = VIML_INTERNAL_CALL + 1;
+
 extern "C" {
     /// Gets the value of a global or local (buffer, window) option.
     ///
@@ -30,3 +42,40 @@ extern "C" {
     ///
     pub fn find_buffer_by_handle(buffer: Buffer, error: *mut LuaError) -> *const buf_T;
 }
+
+#[cfg(not(any(test, feature = "stub_luaref")))]
+extern "C" {
+    /// Releases `luaref` from the Lua registry; `LUA_NOREF` is ignored. `Object`s that wrap a
+    /// `LuaRef` call this when they're dropped.
+    ///
+    pub fn api_free_luaref(luaref: LuaRef);
+
+    /// Creates a new reference to the same Lua value as `luaref`, which needs to be released
+    /// separately; `LUA_NOREF` stays `LUA_NOREF`. `Object`s that wrap a `LuaRef` call this when
+    /// they're cloned.
+    ///
+    pub fn api_new_luaref(luaref: LuaRef) -> LuaRef;
+}
+
+/// Stand-in for nvim's `api_free_luaref()`, for tests that run outside of nvim (where there's no
+/// Lua registry); it does nothing.
+///
+/// # Safety
+///
+/// This is always safe to call; it's `unsafe` to match nvim's function.
+///
+#[cfg(any(test, feature = "stub_luaref"))]
+pub const unsafe fn api_free_luaref(_luaref: LuaRef) {}
+
+/// Stand-in for nvim's `api_new_luaref()`, for tests that run outside of nvim; it returns
+/// `luaref`.
+///
+/// # Safety
+///
+/// This is always safe to call; it's `unsafe` to match nvim's function.
+///
+#[cfg(any(test, feature = "stub_luaref"))]
+#[must_use]
+pub const unsafe fn api_new_luaref(luaref: LuaRef) -> LuaRef {
+    luaref
+}
@@ -0,0 +1,73 @@
+//!
+//! This module contains function wrappers that are defined in `neovim/src/nvim/api/tabpage.c`.
+//!
+use super::{
+    nvim::{Array, Boolean, Integer, LuaError, NvimString, Object},
+    window::Window,
+};
+use crate::types::handle_T;
+use std::os::raw::c_int;
+
+extern "C" {
+    /// Gets the windows in a tabpage. Returns an `Array` of `Window`s.
+    ///
+    pub fn nvim_tabpage_list_wins(tabpage: Tabpage, err: *mut LuaError) -> Array;
+
+    /// Gets the current window in a tabpage.
+    ///
+    pub fn nvim_tabpage_get_win(tabpage: Tabpage, err: *mut LuaError) -> Window;
+
+    /// Gets the tabpage number (what `tabpagenr()` would return).
+    ///
+    pub fn nvim_tabpage_get_number(tabpage: Tabpage, err: *mut LuaError) -> Integer;
+
+    /// Gets a tab-scoped (t:) variable.
+    ///
+    pub fn nvim_tabpage_get_var(tabpage: Tabpage, name: NvimString, err: *mut LuaError) -> Object;
+
+    /// Sets a tab-scoped (t:) variable.
+    ///
+    pub fn nvim_tabpage_set_var(
+        tabpage: Tabpage,
+        name: NvimString,
+        value: Object,
+        err: *mut LuaError,
+    );
+
+    /// Removes a tab-scoped (t:) variable.
+    ///
+    pub fn nvim_tabpage_del_var(tabpage: Tabpage, name: NvimString, err: *mut LuaError);
+
+    /// Checks if a tabpage is valid.
+    ///
+    pub fn nvim_tabpage_is_valid(tabpage: Tabpage) -> Boolean;
+}
+
+/// The tabpage handle (not the tabpage number).
+///
+/// Like `Window`, it has the same layout as nvim's `handle_T`, but is its own type so that other
+/// handles can't be passed where a tabpage is expected.
+///
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tabpage(handle_T);
+
+impl Tabpage {
+    /// nvim treats tabpage `0` as "the current tabpage" in all API calls; this is that tabpage.
+    ///
+    pub const CURRENT: Self = Self(0);
+
+    /// Wraps the tabpage handle `handle`.
+    ///
+    #[must_use]
+    pub const fn new(handle: c_int) -> Self {
+        Self(handle)
+    }
+
+    /// The tabpage handle.
+    ///
+    #[must_use]
+    pub const fn handle(self) -> c_int {
+        self.0
+    }
+}
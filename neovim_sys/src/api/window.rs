@@ -0,0 +1,74 @@
+//!
+//! This module contains function wrappers that are defined in `neovim/src/nvim/api/window.c`.
+//!
+use super::{
+    buffer::Buffer,
+    nvim::{Array, Boolean, Integer, LuaError, NvimString, Object},
+};
+use crate::types::handle_T;
+use std::os::raw::c_int;
+
+extern "C" {
+    /// Gets the (1,0)-indexed cursor position in the window. Returns an `Array` of two
+    /// `Integer`s: `[row, col]`.
+    ///
+    pub fn nvim_win_get_cursor(window: Window, err: *mut LuaError) -> Array;
+
+    /// Sets the (1,0)-indexed cursor position in the window. `pos` should be an `Array` of two
+    /// `Integer`s: `[row, col]`.
+    ///
+    pub fn nvim_win_set_cursor(window: Window, pos: Array, err: *mut LuaError);
+
+    /// Gets the current buffer in a window.
+    ///
+    pub fn nvim_win_get_buf(window: Window, err: *mut LuaError) -> Buffer;
+
+    /// Gets the window width.
+    ///
+    pub fn nvim_win_get_width(window: Window, err: *mut LuaError) -> Integer;
+
+    /// Gets the window height.
+    ///
+    pub fn nvim_win_get_height(window: Window, err: *mut LuaError) -> Integer;
+
+    /// Closes the window (like `:close` with a window-ID).
+    ///
+    pub fn nvim_win_close(window: Window, force: Boolean, err: *mut LuaError);
+
+    /// Gets a window-scoped (w:) variable.
+    ///
+    pub fn nvim_win_get_var(window: Window, name: NvimString, err: *mut LuaError) -> Object;
+
+    /// Sets a window-scoped (w:) variable.
+    ///
+    pub fn nvim_win_set_var(window: Window, name: NvimString, value: Object, err: *mut LuaError);
+}
+
+/// The window handle (aka "window ID"; not the window number).
+///
+/// It has the same layout as nvim's `handle_T`, but is its own type so that, for example, a buffer
+/// handle can't be passed where a window is expected.
+///
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Window(handle_T);
+
+impl Window {
+    /// nvim treats window `0` as "the current window" in all API calls; this is that window.
+    ///
+    pub const CURRENT: Self = Self(0);
+
+    /// Wraps the window handle `handle`.
+    ///
+    #[must_use]
+    pub const fn new(handle: c_int) -> Self {
+        Self(handle)
+    }
+
+    /// The window handle.
+    ///
+    #[must_use]
+    pub const fn handle(self) -> c_int {
+        self.0
+    }
+}
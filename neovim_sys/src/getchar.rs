@@ -1,7 +1,15 @@
 //! Types and functions related to those in nvim/getchar.c.
 //!
-use crate::{buffer_defs::buf_T, types::CharU};
-use std::{os::raw::c_int};
+use crate::{
+    api::nvim::{LuaRef, LUA_NOREF},
+    buffer_defs::buf_T,
+    memory,
+    types::CharU,
+};
+use std::{
+    os::raw::{c_char, c_int},
+    ptr,
+};
 
 /// Flag, specifically for `do_map()` and `buf_do_map()`.
 ///
@@ -17,21 +25,61 @@ pub enum MapType {
     NoRemap = 2,
 }
 
+/// Maximum length of a mapping's `lhs`, in bytes (`MAXMAPLEN`).
+///
+pub const MAXMAPLEN: usize = 50;
+
 /// Some vim map-related calls require the mapping arguments (that were provided via a string) be
 /// parsed into a struct--this is that struct. See `str_to_mapargs()`.
 ///
+/// nvim writes the whole struct, so this mirrors its layout in `getchar.h`. The `rhs` and
+/// `orig_rhs` that nvim allocates are freed on drop.
+///
 #[allow(missing_copy_implementations)]
 #[repr(C)]
 pub struct MapArguments {
-    _inner: [u8; 0],
+    buffer: bool,
+    expr: bool,
+    noremap: bool,
+    nowait: bool,
+    script: bool,
+    silent: bool,
+    unique: bool,
+    lhs: [CharU; MAXMAPLEN + 1],
+    lhs_len: usize,
+    rhs: *mut CharU,
+    rhs_len: usize,
+    rhs_lua: LuaRef,
+    rhs_is_noop: bool,
+    orig_rhs: *mut CharU,
+    orig_rhs_len: usize,
+    desc: *mut c_char,
 }
 
 impl MapArguments {
-    /// An empty struct that must be passed to a nvim function to be initialized.
+    /// An empty struct that must be passed to a nvim function to be initialized; the same as
+    /// nvim's `MAP_ARGUMENTS_INIT`.
     ///
     #[must_use]
     pub const fn new() -> Self {
-        Self { _inner: [] }
+        Self {
+            buffer: false,
+            expr: false,
+            noremap: false,
+            nowait: false,
+            script: false,
+            silent: false,
+            unique: false,
+            lhs: [0; MAXMAPLEN + 1],
+            lhs_len: 0,
+            rhs: ptr::null_mut(),
+            rhs_len: 0,
+            rhs_lua: LUA_NOREF,
+            rhs_is_noop: false,
+            orig_rhs: ptr::null_mut(),
+            orig_rhs_len: 0,
+            desc: ptr::null_mut(),
+        }
     }
 }
 
@@ -41,6 +89,16 @@ impl Default for MapArguments {
     }
 }
 
+impl Drop for MapArguments {
+    fn drop(&mut self) {
+        // Same as what `do_map()` frees after calling `buf_do_map()`.
+        unsafe {
+            memory::xfree(self.rhs.cast());
+            memory::xfree(self.orig_rhs.cast());
+        }
+    }
+}
+
 extern "C" {
     /// `map_type` is one of `MapType`.
     /// `arg` is the argument to the mapping (this C-string will be modified).
@@ -79,4 +137,10 @@ extern "C" {
     /// - 0: success
     /// - 1: invalid arguments
     pub fn str_to_mapargs(args: *const CharU, is_unmap: bool, mapargs: *mut MapArguments) -> c_int;
+
+    /// Clears all mappings (or abbreviations, if `is_abbrev`) for `mode`; the internals of
+    /// `:mapclear`. If `local` is `true`, only `buf`'s buffer-local mappings get cleared (`buf` is
+    /// obtained the same way as for `buf_do_map()`).
+    ///
+    pub fn map_clear_int(buf: *const buf_T, mode: c_int, local: bool, is_abbrev: bool);
 }
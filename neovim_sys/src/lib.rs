@@ -26,6 +26,7 @@ pub mod api;
 pub mod buffer_defs;
 pub mod getchar;
 pub mod globals;
+pub mod memory;
 pub mod option;
 pub mod vim;
 pub mod types;
@@ -0,0 +1,9 @@
+//! Functions from `nvim/memory.c`.
+//!
+use std::ffi::c_void;
+
+extern "C" {
+    /// Frees memory that nvim allocated (ex. with `xmalloc()`). `ptr` may be null.
+    ///
+    pub fn xfree(ptr: *mut c_void);
+}
@@ -2,10 +2,19 @@
 //! This module contains function wrappers for neovim functions defined in
 //! `neovim/src/nvim/api/buffer.c`.
 //!
-use super::{Buffer, Error};
+use super::{
+    extmark::{Extmark, ExtmarkOpts, ExtmarkPosition},
+    Buffer, Error, Integer,
+};
 use neovim_sys::api::{
     self,
-    nvim::{LuaError, NvimString, Object},
+    keysets::KeyDict_set_extmark,
+    nvim::{Array, Dictionary, KeyValuePair, LuaError, NvimString, Object},
+    private::LUA_INTERNAL_CALL,
+};
+use std::{
+    convert::TryFrom,
+    ops::{Bound, Range, RangeBounds},
 };
 
 /// # Errors
@@ -64,6 +73,460 @@ pub fn nvim_buf_get_option(buffer: Buffer, name: &str) -> Result<Object, Error>
     }
 }
 
+/// Deletes the buffer (see `:bwipeout`). With `force`, unsaved changes are discarded; with
+/// `unload`, the buffer is only unloaded (see `:bunload`).
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. the buffer has unsaved changes and `force` is `false`).
+///
+pub fn nvim_buf_delete(buffer: Buffer, force: bool, unload: bool) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let opts = Dictionary::new_from([
+        KeyValuePair::new(NvimString::new_unchecked("force"), Object::from(force)),
+        KeyValuePair::new(NvimString::new_unchecked("unload"), Object::from(unload)),
+    ]);
+
+    unsafe {
+        api::buffer::nvim_buf_delete(buffer, opts, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Gets the full file name of the buffer. For unnamed buffers, this is a null string (its
+/// `as_ptr()` is null), so check for that before converting it.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_buf_get_name(buffer: Buffer) -> Result<NvimString, Error> {
+    let mut out_err = LuaError::default();
+
+    let name = unsafe { api::buffer::nvim_buf_get_name(buffer, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(name)
+    }
+}
+
+/// Sets the full file name of the buffer.
+///
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. another buffer already has that name).
+///
+pub fn nvim_buf_set_name(buffer: Buffer, name: &str) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    unsafe {
+        api::buffer::nvim_buf_set_name(buffer, api_name, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if the buffer is valid and loaded.
+///
+#[must_use]
+pub fn nvim_buf_is_loaded(buffer: Buffer) -> bool {
+    unsafe { api::buffer::nvim_buf_is_loaded(buffer) }
+}
+
+/// Checks if the buffer is valid. Note that a valid buffer may still be unloaded.
+///
+#[must_use]
+pub fn nvim_buf_is_valid(buffer: Buffer) -> bool {
+    unsafe { api::buffer::nvim_buf_is_valid(buffer) }
+}
+
+/// Gets the buffer's `b:changedtick`, which is incremented for every change to the buffer.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_buf_get_changedtick(buffer: Buffer) -> Result<Integer, Error> {
+    let mut out_err = LuaError::default();
+
+    let changedtick = unsafe { api::buffer::nvim_buf_get_changedtick(buffer, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(changedtick)
+    }
+}
+
+/// Gets the number of lines in the buffer.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_buf_line_count(buffer: Buffer) -> Result<Integer, Error> {
+    let mut out_err = LuaError::default();
+
+    let count = unsafe { api::buffer::nvim_buf_line_count(buffer, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(count)
+    }
+}
+
+/// Gets the lines in `range` from the buffer.
+///
+/// Lines are zero-indexed and negative indices count back from the end of the buffer, like Python
+/// slices: `..` is the whole buffer, `-2..` is the last two lines, `..-1` is everything but the
+/// last line, and `..=-1` is the whole buffer again.
+///
+/// With `strict_indexing`, an out-of-bounds `range` is an error; otherwise it gets clamped.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+/// * If nvim returned something other than strings.
+///
+pub fn nvim_buf_get_lines<R>(
+    buffer: Buffer,
+    range: R,
+    strict_indexing: bool,
+) -> Result<Vec<String>, Error>
+where
+    R: RangeBounds<Integer>,
+{
+    let mut out_err = LuaError::default();
+    let (start, end) = to_nvim_line_range(&range);
+
+    let lines = unsafe {
+        api::buffer::nvim_buf_get_lines(
+            LUA_INTERNAL_CALL,
+            buffer,
+            start,
+            end,
+            strict_indexing,
+            &mut out_err,
+        )
+    };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        lines_to_strings(lines)
+    }
+}
+
+/// Replaces the lines in `range` with `replacement`, using the same indexing as
+/// `nvim_buf_get_lines()`.
+///
+/// An empty `range` (ex. `3..3`) inserts `replacement` before that line; an empty `replacement`
+/// deletes the lines.
+///
+/// # Errors
+///
+/// * If any line in `replacement` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. a line contains a newline).
+///
+pub fn nvim_buf_set_lines<R, S>(
+    buffer: Buffer,
+    range: R,
+    strict_indexing: bool,
+    replacement: &[S],
+) -> Result<(), Error>
+where
+    R: RangeBounds<Integer>,
+    S: AsRef<str>,
+{
+    let mut out_err = LuaError::default();
+    let (start, end) = to_nvim_line_range(&range);
+    let api_replacement = strings_to_lines(replacement)?;
+
+    unsafe {
+        api::buffer::nvim_buf_set_lines(
+            LUA_INTERNAL_CALL,
+            buffer,
+            start,
+            end,
+            strict_indexing,
+            api_replacement,
+            &mut out_err,
+        );
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Gets the text between two zero-indexed `(row, col)` positions; the `end` position is
+/// exclusive.
+///
+/// This is for getting partial lines; use `nvim_buf_get_lines()` for whole lines.
+///
+/// Note that this requires nvim 0.7 or later.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. the range is out of bounds).
+/// * If nvim returned something other than strings.
+///
+pub fn nvim_buf_get_text(
+    buffer: Buffer,
+    range: Range<(Integer, Integer)>,
+) -> Result<Vec<String>, Error> {
+    let mut out_err = LuaError::default();
+    let Range {
+        start: (start_row, start_col),
+        end: (end_row, end_col),
+    } = range;
+
+    let text = unsafe {
+        api::buffer::nvim_buf_get_text(
+            LUA_INTERNAL_CALL,
+            buffer,
+            start_row,
+            start_col,
+            end_row,
+            end_col,
+            Dictionary::default(),
+            &mut out_err,
+        )
+    };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        lines_to_strings(text)
+    }
+}
+
+/// Replaces the text between two zero-indexed `(row, col)` positions with `replacement`; the
+/// `end` position is exclusive.
+///
+/// An empty `range` inserts `replacement` at that position.
+///
+/// # Errors
+///
+/// * If any line in `replacement` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. the range is out of bounds).
+///
+pub fn nvim_buf_set_text<S>(
+    buffer: Buffer,
+    range: Range<(Integer, Integer)>,
+    replacement: &[S],
+) -> Result<(), Error>
+where
+    S: AsRef<str>,
+{
+    let mut out_err = LuaError::default();
+    let Range {
+        start: (start_row, start_col),
+        end: (end_row, end_col),
+    } = range;
+    let api_replacement = strings_to_lines(replacement)?;
+
+    unsafe {
+        api::buffer::nvim_buf_set_text(
+            LUA_INTERNAL_CALL,
+            buffer,
+            start_row,
+            start_col,
+            end_row,
+            end_col,
+            api_replacement,
+            &mut out_err,
+        );
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Creates an extmark in namespace `ns_id` at the zero-indexed `line` and `col`, or updates it if
+/// `opts` has an `id` for an existing extmark.
+///
+/// Returns the extmark's ID.
+///
+/// # Errors
+///
+/// * If any strings in `opts` can't be converted to `NvimString`s.
+/// * If nvim set an error on the call (ex. the position is out of bounds).
+///
+pub fn nvim_buf_set_extmark(
+    buffer: Buffer,
+    ns_id: Integer,
+    line: Integer,
+    col: Integer,
+    opts: ExtmarkOpts,
+) -> Result<Integer, Error> {
+    let mut out_err = LuaError::default();
+    let keyset = KeyDict_set_extmark::try_from(opts)?;
+
+    let id = unsafe {
+        api::buffer::nvim_buf_set_extmark(buffer, ns_id, line, col, &keyset, &mut out_err)
+    };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(id)
+    }
+}
+
+/// Gets the extmarks in namespace `ns_id` between `start` and `end`.
+///
+/// `limit` caps the number of extmarks returned; with `details`, each `Extmark` will include its
+/// options.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. the namespace doesn't exist).
+/// * If nvim returned something other than extmarks.
+///
+pub fn nvim_buf_get_extmarks(
+    buffer: Buffer,
+    ns_id: Integer,
+    start: ExtmarkPosition,
+    end: ExtmarkPosition,
+    limit: Option<Integer>,
+    details: bool,
+) -> Result<Vec<Extmark>, Error> {
+    let mut out_err = LuaError::default();
+    let mut opts = Dictionary::new_from([KeyValuePair::new(
+        NvimString::new_unchecked("details"),
+        Object::from(details),
+    )]);
+
+    if let Some(limit) = limit {
+        opts.set(NvimString::new_unchecked("limit"), limit);
+    }
+
+    let extmarks = unsafe {
+        api::buffer::nvim_buf_get_extmarks(
+            buffer,
+            ns_id,
+            Object::from(start),
+            Object::from(end),
+            opts,
+            &mut out_err,
+        )
+    };
+
+    if out_err.is_err() {
+        return Err(Error::from(out_err));
+    }
+
+    extmarks
+        .into_iter()
+        .map(|extmark| Extmark::try_from(extmark).map_err(Error::from))
+        .collect()
+}
+
+/// Removes extmark `id` from namespace `ns_id`. Returns `false` if the extmark didn't exist.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. the namespace doesn't exist).
+///
+pub fn nvim_buf_del_extmark(buffer: Buffer, ns_id: Integer, id: Integer) -> Result<bool, Error> {
+    let mut out_err = LuaError::default();
+
+    let found = unsafe { api::buffer::nvim_buf_del_extmark(buffer, ns_id, id, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(found)
+    }
+}
+
+/// Clears extmarks, highlights, and virtual text in namespace `ns_id` (or all namespaces, if
+/// `-1`) from the lines in `range`.
+///
+/// `range` is zero-indexed; nvim only understands non-negative indices here, other than an
+/// unbounded end.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_buf_clear_namespace<R>(buffer: Buffer, ns_id: Integer, range: R) -> Result<(), Error>
+where
+    R: RangeBounds<Integer>,
+{
+    let mut out_err = LuaError::default();
+    let (line_start, line_end) = to_nvim_line_range(&range);
+
+    unsafe {
+        api::buffer::nvim_buf_clear_namespace(buffer, ns_id, line_start, line_end, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Converts a Python-like line range into nvim's zero-based, end-exclusive `(start, end)`, where
+/// negative indices mean `length + 1 + index`.
+///
+fn to_nvim_line_range<R: RangeBounds<Integer>>(range: &R) -> (Integer, Integer) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) if s < 0 => s - 1,
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) if s < 0 => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&e) if e < 0 => e,
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) if e < 0 => e - 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => -1,
+    };
+
+    (start, end)
+}
+
+fn lines_to_strings(lines: Array) -> Result<Vec<String>, Error> {
+    lines
+        .into_iter()
+        .map(|line| String::try_from(line).map_err(Error::from))
+        .collect()
+}
+
+fn strings_to_lines<S: AsRef<str>>(strings: &[S]) -> Result<Array, Error> {
+    let lines = strings
+        .iter()
+        .map(|s| NvimString::new(s.as_ref()).map(Object::from))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Array::new_from(lines))
+}
+
 // pub fn nvim_buf_set_option(_buffer: Buffer, _name: &str, _value: Object) -> Result<(), Error> {
 //     // let api_name = NvimString::new(name)?;
 //     // let mut out_err = LuaError::default();
@@ -76,3 +539,39 @@ pub fn nvim_buf_get_option(buffer: Buffer, name: &str) -> Result<Object, Error>
 //     //     Ok(())
 //     // }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_nvim_line_range() {
+        // Whole buffer
+        assert_eq!(to_nvim_line_range(&(..)), (0, -1));
+        assert_eq!(to_nvim_line_range(&(0..)), (0, -1));
+        assert_eq!(to_nvim_line_range(&(..=-1)), (0, -1));
+
+        // Positive indices
+        assert_eq!(to_nvim_line_range(&(1..3)), (1, 3));
+        assert_eq!(to_nvim_line_range(&(1..=3)), (1, 4));
+        assert_eq!(to_nvim_line_range(&(..2)), (0, 2));
+        assert_eq!(to_nvim_line_range(&(2..2)), (2, 2));
+
+        // Negative indices
+        assert_eq!(to_nvim_line_range(&(-1..)), (-2, -1));
+        assert_eq!(to_nvim_line_range(&(-2..)), (-3, -1));
+        assert_eq!(to_nvim_line_range(&(..-1)), (0, -2));
+        assert_eq!(to_nvim_line_range(&(-3..-1)), (-4, -2));
+        assert_eq!(to_nvim_line_range(&(-3..=-2)), (-4, -2));
+
+        // Excluded start
+        assert_eq!(
+            to_nvim_line_range(&(Bound::Excluded(0), Bound::Unbounded)),
+            (1, -1)
+        );
+        assert_eq!(
+            to_nvim_line_range(&(Bound::Excluded(-2), Bound::Unbounded)),
+            (-2, -1)
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//!
+//! This module contains the registry for Rust closures that get called from Lua (and so can be
+//! used anywhere nvim takes a `LuaRef`: keymaps, autocmds, user commands, timers...).
+//!
+//! nvim doesn't expose its `lua_State`, so a `Callback` is a Lua function (created with
+//! `nvim_exec_lua()`) that calls back into Rust through `LuaJIT`'s FFI. The function gets stored in
+//! the Lua registry, the same way nvim does it, and that registry index is the `LuaRef`. When the
+//! last reference to the function is released and Lua garbage-collects it, the closure gets
+//! dropped.
+//!
+//! ```no_run
+//! use nvim_api::{callback::Callback, Array, Object};
+//!
+//! let mut count = 0;
+//! let callback = Callback::new(move |_args: Array| {
+//!     count += 1;
+//!     Ok(Object::from(count))
+//! })
+//! .unwrap();
+//!
+//! assert_eq!(callback.call(Array::new()).unwrap(), Object::from(1));
+//! ```
+//!
+use crate::{nvim, sys::api::private, Array, Error, Integer, LuaRef, NvimString, Object};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
+
+type BoxedCallback = Box<dyn FnMut(Array) -> Result<Object, Error>>;
+
+thread_local! {
+    static CALLBACKS: RefCell<HashMap<u64, Rc<RefCell<BoxedCallback>>>> =
+        RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+/// Creates the Lua function for callback `id` and stores it in the Lua registry. What this does
+/// with the registry is exactly `luaL_ref(L, LUA_REGISTRYINDEX)`, which is how nvim itself creates
+/// `LuaRef`s, so nvim can use (and release) the result like any other `LuaRef`.
+///
+const REGISTER_LUA: &str = r"
+local id, invoke_ptr, drop_ptr = ...
+local ffi = require('ffi')
+local state = _G.__nvim_api_callbacks
+if state == nil then
+    ffi.cdef([[
+        typedef bool (*nvim_api_callback_invoke_t)(uint64_t);
+        typedef void (*nvim_api_callback_drop_t)(uint64_t);
+    ]])
+    state = { args = {} }
+    _G.__nvim_api_callbacks = state
+end
+local invoke = ffi.cast('nvim_api_callback_invoke_t', invoke_ptr)
+local drop = ffi.cast('nvim_api_callback_drop_t', drop_ptr)
+
+local guard = newproxy(true)
+getmetatable(guard).__gc = function() drop(id) end
+
+local callback = function(...)
+    local _ = guard
+    table.insert(state.args, { ... })
+    local ok = invoke(id)
+    table.remove(state.args)
+    local result, err = state.result, state.err
+    state.result, state.err = nil, nil
+    if not ok then
+        error(err, 2)
+    end
+    return result
+end
+-- Calling back into Lua from an FFI call only works if the call isn't JIT-compiled.
+jit.off(callback)
+
+local registry = debug.getregistry()
+local ref = tonumber(rawget(registry, 0)) or 0
+if ref ~= 0 then
+    rawset(registry, 0, rawget(registry, ref))
+else
+    ref = #registry + 1
+end
+rawset(registry, ref, callback)
+return ref
+";
+
+/// Exactly `luaL_unref(L, LUA_REGISTRYINDEX, ref)`.
+///
+const UNREF_LUA: &str = r"
+local ref = ...
+local registry = debug.getregistry()
+if ref > 0 then
+    rawset(registry, ref, rawget(registry, 0))
+    rawset(registry, 0, ref)
+end
+";
+
+const CALL_LUA: &str = "local ref = ...; return debug.getregistry()[ref](select(2, ...))";
+const GET_ARGS_LUA: &str = "local args = _G.__nvim_api_callbacks.args; return args[#args]";
+const SET_RESULT_LUA: &str = "_G.__nvim_api_callbacks.result = ...";
+const SET_ERROR_LUA: &str = "_G.__nvim_api_callbacks.err = ...";
+
+/// A Rust closure that's callable from Lua.
+///
+/// It owns a `LuaRef` to the Lua function that wraps the closure; dropping the `Callback` releases
+/// that reference. nvim takes its own reference when it's given a `LuaRef` (ex. as a keymap's
+/// `callback`), so the closure lives on for as long as nvim still uses it.
+///
+/// When the closure returns an `Err` (or panics), the Lua function raises an error with its
+/// message.
+///
+#[derive(Debug)]
+pub struct Callback {
+    luaref: LuaRef,
+}
+
+impl Callback {
+    /// Registers `f` and creates the Lua function that calls it. The Lua function's arguments are
+    /// passed to `f` as an `Array`, and the `Object` that `f` returns is returned to Lua.
+    ///
+    /// Note that, since the arguments are passed as a Lua list, any trailing `nil`s get dropped.
+    ///
+    /// # Errors
+    ///
+    /// * If creating the Lua function fails.
+    ///
+    pub fn new<F>(f: F) -> Result<Self, Error>
+    where
+        F: FnMut(Array) -> Result<Object, Error> + 'static,
+    {
+        let id = NEXT_ID.with(|next_id| {
+            let id = next_id.get();
+            next_id.set(id + 1);
+            id
+        });
+
+        CALLBACKS.with(|callbacks| {
+            let boxed: BoxedCallback = Box::new(f);
+            callbacks
+                .borrow_mut()
+                .insert(id, Rc::new(RefCell::new(boxed)));
+        });
+
+        #[allow(clippy::cast_possible_wrap)]
+        let args = Array::new_from([
+            Object::from(id as Integer),
+            Object::from(invoke as *const () as usize as Integer),
+            Object::from(drop_callback as *const () as usize as Integer),
+        ]);
+
+        let luaref = nvim::nvim_exec_lua(REGISTER_LUA, args).and_then(|object| {
+            let integer = object.try_into_integer()?;
+            LuaRef::try_from(integer).map_err(|e| Error::Raw(e.to_string()))
+        });
+
+        if luaref.is_err() {
+            drop_callback(id);
+        }
+
+        luaref.map(|luaref| Self { luaref })
+    }
+
+    /// The reference to the Lua function. It's only valid for as long as `self` is alive.
+    ///
+    #[must_use]
+    pub const fn luaref(&self) -> LuaRef {
+        self.luaref
+    }
+
+    /// Gives up ownership of the reference, for when nvim takes it over (and so releases it when
+    /// it's done with it).
+    ///
+    #[must_use]
+    pub const fn into_luaref(self) -> LuaRef {
+        let luaref = self.luaref;
+        std::mem::forget(self);
+        luaref
+    }
+
+    /// Calls the Lua function (and thus the closure) with `args`.
+    ///
+    /// # Errors
+    ///
+    /// * If the closure returns an error or panics.
+    ///
+    pub fn call(&self, args: Array) -> Result<Object, Error> {
+        let call_args: Vec<Object> = std::iter::once(Object::from(Integer::from(self.luaref)))
+            .chain(args)
+            .collect();
+
+        nvim::nvim_exec_lua(CALL_LUA, Array::new_from(call_args))
+    }
+}
+
+impl Drop for Callback {
+    fn drop(&mut self) {
+        // There's nothing to do about an error here; worst case, the closure is leaked.
+        let _ = nvim::nvim_exec_lua(
+            UNREF_LUA,
+            Array::new_from([Object::from(Integer::from(self.luaref))]),
+        );
+    }
+}
+
+/// The `Object` gets its own reference to the Lua function, so it can outlive the `Callback`.
+///
+impl From<&Callback> for Object {
+    fn from(callback: &Callback) -> Self {
+        Self::new_luaref(unsafe { private::api_new_luaref(callback.luaref) })
+    }
+}
+
+/// Called (from Lua) when the Lua function for callback `id` is called. This must not unwind, so
+/// panics are caught and, like errors, handed back to Lua as the error message.
+///
+extern "C" fn invoke(id: u64) -> bool {
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| run_callback(id)))
+        .unwrap_or_else(|payload| Err(Error::Raw(panic_message(payload.as_ref()))));
+
+    match outcome {
+        Ok(result) => set_state(SET_RESULT_LUA, result),
+        Err(e) => {
+            let message = e.to_string().replace('\0', "");
+            set_state(
+                SET_ERROR_LUA,
+                Object::from(NvimString::new_unchecked(message)),
+            );
+            false
+        }
+    }
+}
+
+/// Called (from Lua) when the Lua function for callback `id` is garbage-collected.
+///
+extern "C" fn drop_callback(id: u64) {
+    // The registry can already be gone if this happens while nvim is exiting.
+    let _ = CALLBACKS.try_with(|callbacks| callbacks.borrow_mut().remove(&id));
+}
+
+fn run_callback(id: u64) -> Result<Object, Error> {
+    let callback = CALLBACKS
+        .with(|callbacks| callbacks.borrow().get(&id).cloned())
+        .ok_or_else(|| Error::Raw(format!("No Rust callback with ID {id}")))?;
+
+    let args = nvim::nvim_exec_lua(GET_ARGS_LUA, Array::new())?.try_into_array()?;
+
+    let mut callback = callback
+        .try_borrow_mut()
+        .map_err(|_| Error::Raw(format!("Rust callback {id} called itself")))?;
+
+    callback(args)
+}
+
+fn set_state(code: &str, value: Object) -> bool {
+    nvim::nvim_exec_lua(code, Array::new_from([value])).is_ok()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("(no message)");
+
+    format!("Rust callback panicked: {message}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panic_message() {
+        let payload = panic::catch_unwind(|| panic!("meow")).unwrap_err();
+        assert_eq!(
+            panic_message(payload.as_ref()),
+            "Rust callback panicked: meow"
+        );
+
+        let payload = panic::catch_unwind(|| panic!("{}", String::from("woof"))).unwrap_err();
+        assert_eq!(
+            panic_message(payload.as_ref()),
+            "Rust callback panicked: woof"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use neovim_sys::api::nvim::{self, LuaError, NvimString};
-use std::ffi::NulError;
+use std::{convert::Infallible, ffi::NulError, fmt};
 
 /// The general error type for handling errors.
 ///
@@ -29,6 +29,132 @@ pub enum Error {
     #[error("v:errmsg: '{}'", .0)]
     VErrMsg(NvimString),
 
+    /// An exception that was thrown while executing Vimscript (ex. from `nvim_exec()`).
+    ///
+    #[error(transparent)]
+    VimException(#[from] VimException),
+
     #[error("Error from vim: {}", _0)]
     Raw(String),
 }
+
+impl From<Infallible> for Error {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+/// A Vimscript exception, parsed from its `v:exception`-style message.
+///
+/// Vim errors look like `Vim(echoerr):E605: message` or `Vim:E492: message`, where the command and
+/// error number are optional; exceptions thrown with `:throw` are just the message.
+///
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct VimException {
+    command: Option<String>,
+    code: Option<u32>,
+    message: String,
+}
+
+impl VimException {
+    /// The Ex command that failed (ex. `echoerr` for `Vim(echoerr):...`), if there was one.
+    ///
+    #[must_use]
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    /// The vim error number (ex. `492` for `E492`), if there was one.
+    ///
+    #[must_use]
+    pub const fn code(&self) -> Option<u32> {
+        self.code
+    }
+
+    /// The rest of the message.
+    ///
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<&str> for VimException {
+    fn from(value: &str) -> Self {
+        let (command, rest) = value.strip_prefix("Vim").map_or((None, value), |rest| {
+            match rest.strip_prefix('(').and_then(|r| r.split_once("):")) {
+                Some((command, rest)) => (Some(command.to_owned()), rest),
+                None => (None, rest.strip_prefix(':').unwrap_or(value)),
+            }
+        });
+
+        let (code, message) = rest
+            .strip_prefix('E')
+            .and_then(|r| r.split_once(": "))
+            .and_then(|(code, message)| code.parse::<u32>().ok().map(|code| (code, message)))
+            .map_or((None, rest), |(code, message)| (Some(code), message));
+
+        Self {
+            command,
+            code,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VimException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.command.is_none() && self.code.is_none() {
+            return f.write_str(&self.message);
+        }
+
+        f.write_str("Vim")?;
+
+        if let Some(ref command) = self.command {
+            write!(f, "({command})")?;
+        }
+
+        f.write_str(":")?;
+
+        if let Some(code) = self.code {
+            write!(f, "E{code}: ")?;
+        }
+
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vim_exception_from_str() {
+        let e = VimException::from("Vim(echoerr):E605: meow");
+        assert_eq!(e.command(), Some("echoerr"));
+        assert_eq!(e.code(), Some(605));
+        assert_eq!(e.message(), "meow");
+        assert_eq!(e.to_string(), "Vim(echoerr):E605: meow");
+
+        let e = VimException::from("Vim:E492: Not an editor command: Meow");
+        assert_eq!(e.command(), None);
+        assert_eq!(e.code(), Some(492));
+        assert_eq!(e.message(), "Not an editor command: Meow");
+        assert_eq!(e.to_string(), "Vim:E492: Not an editor command: Meow");
+
+        let e = VimException::from("Vim(echoerr):meow");
+        assert_eq!(e.command(), Some("echoerr"));
+        assert_eq!(e.code(), None);
+        assert_eq!(e.message(), "meow");
+
+        let e = VimException::from("my exception");
+        assert_eq!(e.command(), None);
+        assert_eq!(e.code(), None);
+        assert_eq!(e.message(), "my exception");
+        assert_eq!(e.to_string(), "my exception");
+
+        let e = VimException::from("Vimmy: not a vim error");
+        assert_eq!(e.command(), None);
+        assert_eq!(e.message(), "Vimmy: not a vim error");
+    }
+}
@@ -0,0 +1,363 @@
+//!
+//! This module contains types for working with extmarks (see `:h api-extended-marks`). The
+//! functions that use these are in `nvim_api::buffer`.
+//!
+use neovim_sys::api::{
+    keysets::KeyDict_set_extmark,
+    nvim::{object, Array, Dictionary, Integer, NvimString, Object},
+};
+use std::{convert::TryFrom, ffi::NulError};
+
+/// A chunk of virtual text: the text, and optionally, the highlight group to highlight it with.
+///
+pub type VirtTextChunk = (String, Option<String>);
+
+/// Where to display virtual text (`virt_text_pos`).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtTextPos {
+    /// Right after the end-of-line character (the default).
+    ///
+    Eol,
+
+    /// Display over the specified column, without shifting the underlying text.
+    ///
+    Overlay,
+
+    /// Display right-aligned in the window.
+    ///
+    RightAlign,
+}
+
+impl VirtTextPos {
+    /// The `str` nvim uses for this position.
+    ///
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Eol => "eol",
+            Self::Overlay => "overlay",
+            Self::RightAlign => "right_align",
+        }
+    }
+}
+
+/// Options for `nvim_buf_set_extmark()`. Only options that are set get sent to nvim.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtmarkOpts {
+    id: Option<Integer>,
+    end_row: Option<Integer>,
+    end_col: Option<Integer>,
+    hl_group: Option<String>,
+    hl_eol: Option<bool>,
+    virt_text: Option<Vec<VirtTextChunk>>,
+    virt_text_pos: Option<VirtTextPos>,
+    virt_lines: Option<Vec<Vec<VirtTextChunk>>>,
+    virt_lines_above: Option<bool>,
+    priority: Option<Integer>,
+    sign_text: Option<String>,
+    sign_hl_group: Option<String>,
+}
+
+macro_rules! extmark_opts_def_copy_fn {
+    ($fn_name:ident, $t:ty) => {
+        #[doc=concat!("Sets ", stringify!($fn_name), " to Some(setting).")]
+        #[must_use]
+        pub const fn $fn_name(self, setting: $t) -> Self {
+            let mut s = self;
+            s.$fn_name = Some(setting);
+            s
+        }
+    };
+}
+
+macro_rules! extmark_opts_def_str_fn {
+    ($fn_name:ident) => {
+        #[doc=concat!("Sets ", stringify!($fn_name), " to Some(setting).")]
+        #[must_use]
+        pub fn $fn_name(self, setting: &str) -> Self {
+            let mut s = self;
+            s.$fn_name = Some(setting.to_owned());
+            s
+        }
+    };
+}
+
+impl ExtmarkOpts {
+    extmark_opts_def_copy_fn!(id, Integer);
+    extmark_opts_def_copy_fn!(end_row, Integer);
+    extmark_opts_def_copy_fn!(end_col, Integer);
+    extmark_opts_def_str_fn!(hl_group);
+    extmark_opts_def_copy_fn!(hl_eol, bool);
+    extmark_opts_def_copy_fn!(virt_text_pos, VirtTextPos);
+    extmark_opts_def_copy_fn!(virt_lines_above, bool);
+    extmark_opts_def_copy_fn!(priority, Integer);
+    extmark_opts_def_str_fn!(sign_text);
+    extmark_opts_def_str_fn!(sign_hl_group);
+
+    /// Sets the virtual text to display, as `(text, hl_group)` chunks.
+    ///
+    #[must_use]
+    pub fn virt_text(self, chunks: &[(&str, Option<&str>)]) -> Self {
+        let mut s = self;
+        s.virt_text = Some(to_owned_chunks(chunks));
+        s
+    }
+
+    /// Sets virtual lines to display, where each line is a list of `(text, hl_group)` chunks.
+    ///
+    #[must_use]
+    pub fn virt_lines(self, lines: &[&[(&str, Option<&str>)]]) -> Self {
+        let mut s = self;
+        s.virt_lines = Some(lines.iter().map(|chunks| to_owned_chunks(chunks)).collect());
+        s
+    }
+}
+
+fn to_owned_chunks(chunks: &[(&str, Option<&str>)]) -> Vec<VirtTextChunk> {
+    chunks
+        .iter()
+        .map(|(text, hl_group)| ((*text).to_owned(), hl_group.map(ToOwned::to_owned)))
+        .collect()
+}
+
+fn chunks_to_object(chunks: &[VirtTextChunk]) -> Result<Object, NulError> {
+    let chunks = chunks
+        .iter()
+        .map(|(text, hl_group)| {
+            let mut chunk = vec![Object::from(NvimString::new(text.as_str())?)];
+
+            if let Some(hl_group) = hl_group {
+                chunk.push(Object::from(NvimString::new(hl_group.as_str())?));
+            }
+
+            Ok(Object::from(Array::new_from(chunk)))
+        })
+        .collect::<Result<Vec<_>, NulError>>()?;
+
+    Ok(Object::from(Array::new_from(chunks)))
+}
+
+fn string_to_object(s: &str) -> Result<Object, NulError> {
+    NvimString::new(s).map(Object::from)
+}
+
+impl TryFrom<ExtmarkOpts> for KeyDict_set_extmark {
+    type Error = NulError;
+
+    fn try_from(value: ExtmarkOpts) -> Result<Self, Self::Error> {
+        let mut keyset = Self::default();
+
+        if let Some(id) = value.id {
+            keyset.id = Object::from(id);
+        }
+
+        if let Some(end_row) = value.end_row {
+            keyset.end_row = Object::from(end_row);
+        }
+
+        if let Some(end_col) = value.end_col {
+            keyset.end_col = Object::from(end_col);
+        }
+
+        if let Some(hl_group) = value.hl_group {
+            keyset.hl_group = string_to_object(&hl_group)?;
+        }
+
+        if let Some(hl_eol) = value.hl_eol {
+            keyset.hl_eol = Object::from(hl_eol);
+        }
+
+        if let Some(virt_text) = value.virt_text {
+            keyset.virt_text = chunks_to_object(&virt_text)?;
+        }
+
+        if let Some(virt_text_pos) = value.virt_text_pos {
+            keyset.virt_text_pos = string_to_object(virt_text_pos.as_str())?;
+        }
+
+        if let Some(virt_lines) = value.virt_lines {
+            let lines = virt_lines
+                .iter()
+                .map(|chunks| chunks_to_object(chunks))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            keyset.virt_lines = Object::from(Array::new_from(lines));
+        }
+
+        if let Some(virt_lines_above) = value.virt_lines_above {
+            keyset.virt_lines_above = Object::from(virt_lines_above);
+        }
+
+        if let Some(priority) = value.priority {
+            keyset.priority = Object::from(priority);
+        }
+
+        if let Some(sign_text) = value.sign_text {
+            keyset.sign_text = string_to_object(&sign_text)?;
+        }
+
+        if let Some(sign_hl_group) = value.sign_hl_group {
+            keyset.sign_hl_group = string_to_object(&sign_hl_group)?;
+        }
+
+        Ok(keyset)
+    }
+}
+
+/// One end of a region for `nvim_buf_get_extmarks()`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtmarkPosition {
+    /// The position of an existing extmark.
+    ///
+    Id(Integer),
+
+    /// A zero-indexed `(row, col)` position.
+    ///
+    Position(Integer, Integer),
+}
+
+impl ExtmarkPosition {
+    /// The start of the buffer.
+    ///
+    pub const START: Self = Self::Position(0, 0);
+
+    /// The end of the buffer.
+    ///
+    pub const END: Self = Self::Position(-1, -1);
+}
+
+impl From<ExtmarkPosition> for Object {
+    fn from(value: ExtmarkPosition) -> Self {
+        match value {
+            ExtmarkPosition::Id(id) => Self::from(id),
+            ExtmarkPosition::Position(row, col) => {
+                Self::from(Array::new_from([Self::from(row), Self::from(col)]))
+            }
+        }
+    }
+}
+
+/// An extmark, as returned by `nvim_buf_get_extmarks()`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extmark {
+    id: Integer,
+    row: Integer,
+    col: Integer,
+    details: Option<Dictionary>,
+}
+
+impl Extmark {
+    /// The extmark's ID.
+    ///
+    #[must_use]
+    pub const fn id(&self) -> Integer {
+        self.id
+    }
+
+    /// The zero-indexed row.
+    ///
+    #[must_use]
+    pub const fn row(&self) -> Integer {
+        self.row
+    }
+
+    /// The zero-indexed column.
+    ///
+    #[must_use]
+    pub const fn col(&self) -> Integer {
+        self.col
+    }
+
+    /// The extmark's options (`end_row`, `hl_group`, `virt_text`, etc.); this is only set if
+    /// `details` was requested.
+    ///
+    #[must_use]
+    pub const fn details(&self) -> Option<&Dictionary> {
+        self.details.as_ref()
+    }
+}
+
+impl TryFrom<Object> for Extmark {
+    type Error = object::Error;
+
+    fn try_from(value: Object) -> Result<Self, Self::Error> {
+        let array = value.try_into_array()?;
+
+        match array.as_slice() {
+            [id, row, col] => Ok(Self {
+                id: id.try_as_integer()?,
+                row: row.try_as_integer()?,
+                col: col.try_as_integer()?,
+                details: None,
+            }),
+            [id, row, col, details] => Ok(Self {
+                id: id.try_as_integer()?,
+                row: row.try_as_integer()?,
+                col: col.try_as_integer()?,
+                details: Some(details.try_as_dictionary()?.clone()),
+            }),
+            _ => Err(object::Error::Value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extmark_opts_into_keyset() {
+        let opts = ExtmarkOpts::default()
+            .end_row(2)
+            .hl_group("Search")
+            .virt_text(&[("meow", Some("Comment")), (" ", None)])
+            .virt_text_pos(VirtTextPos::RightAlign);
+
+        let keyset = KeyDict_set_extmark::try_from(opts).unwrap();
+
+        assert!(keyset.id.is_nil());
+        assert_eq!(keyset.end_row, Object::from(2));
+        assert_eq!(
+            keyset.hl_group,
+            Object::from(NvimString::new_unchecked("Search"))
+        );
+        assert_eq!(
+            keyset.virt_text,
+            Object::from(Array::new_from([
+                Object::from(Array::new_from([
+                    Object::from(NvimString::new_unchecked("meow")),
+                    Object::from(NvimString::new_unchecked("Comment")),
+                ])),
+                Object::from(Array::new_from([Object::from(NvimString::new_unchecked(
+                    " "
+                ))])),
+            ]))
+        );
+        assert_eq!(
+            keyset.virt_text_pos,
+            Object::from(NvimString::new_unchecked("right_align"))
+        );
+    }
+
+    #[test]
+    fn test_extmark_try_from_object() {
+        let object = Object::from(Array::new_from([
+            Object::from(1),
+            Object::from(2),
+            Object::from(3),
+        ]));
+        let extmark = Extmark::try_from(object).unwrap();
+
+        assert_eq!(extmark.id(), 1);
+        assert_eq!(extmark.row(), 2);
+        assert_eq!(extmark.col(), 3);
+        assert!(extmark.details().is_none());
+
+        let object = Object::from(Array::new_from([Object::from(1), Object::from(2)]));
+        assert!(Extmark::try_from(object).is_err());
+    }
+}
@@ -1,9 +1,12 @@
+use crate::callback::Callback;
 use core::fmt;
 use neovim_sys::{
     api::{
+        self,
         buffer::Buffer,
-        nvim::{object, Dictionary, LuaError, NvimString},
-        private,
+        keysets::KeyDict_keymap,
+        nvim::{object, Array, Dictionary, LuaError, NvimString, Object},
+        private::{self, LUA_INTERNAL_CALL, VIML_INTERNAL_CALL},
     },
     getchar::{self, MapArguments, MapType},
     vim::{State, UnexpectedState},
@@ -120,6 +123,18 @@ pub enum Error {
 
     #[error(transparent)]
     UnexpectedMode(#[from] UnexpectedState),
+
+    #[error(transparent)]
+    ApiError(#[from] crate::Error),
+
+    /// When a mapping `Dictionary` from nvim (ex. from `get_maps()`) is missing a key, or the
+    /// key's value isn't of the expected type.
+    ///
+    #[error("Mapping is missing key '{key}' (or it isn't {expected})")]
+    MissingKey {
+        key: &'static str,
+        expected: &'static str,
+    },
 }
 
 /// Defines a mapping for `mode` that maps `lhs` to `rhs`.
@@ -137,7 +152,7 @@ pub fn set_map(
     rhs: &str,
     special_arguments: Option<SpecialArguments>,
 ) -> Result<(), Error> {
-    map(MapType::Map, mode, lhs, rhs, special_arguments)
+    map(MapType::Map, mode, lhs, rhs, special_arguments, false)
 }
 
 /// Defines a `noremap` mapping for `mode` that maps `lhs` to `rhs`.
@@ -155,7 +170,39 @@ pub fn set_noremap(
     rhs: &str,
     special_arguments: Option<SpecialArguments>,
 ) -> Result<(), Error> {
-    map(MapType::NoRemap, mode, lhs, rhs, special_arguments)
+    map(MapType::NoRemap, mode, lhs, rhs, special_arguments, false)
+}
+
+/// Deletes the mapping for `lhs` in `mode`; like `:unmap`. Only `buffer` is used from
+/// `special_arguments`, to delete the current buffer's mapping (like `:unmap <buffer>`).
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` can't be coerced to a `CString`.
+/// - There's no mapping for `lhs` in `mode` (`Error::NoMatches`).
+///
+pub fn del_map(
+    mode: &str,
+    lhs: &str,
+    special_arguments: Option<SpecialArguments>,
+) -> Result<(), Error> {
+    let special_arguments = special_arguments
+        .filter(SpecialArguments::is_buffer)
+        .map(|_| SpecialArguments::default().buffer());
+
+    map(MapType::Unmap, mode, lhs, "", special_arguments, false)
+}
+
+/// Deletes all mappings in `mode`; like `:mapclear`. Buffer-local mappings are left alone.
+///
+/// # Errors
+///
+/// This will error if `mode` isn't a known mode.
+///
+pub fn clear_maps(mode: &str) -> Result<(), Error> {
+    clear(Buffer::CURRENT, mode, false, false)
 }
 
 fn map(
@@ -164,17 +211,9 @@ fn map(
     lhs: &str,
     rhs: &str,
     special_arguments: Option<SpecialArguments>,
+    is_abbrev: bool,
 ) -> Result<(), Error> {
-    let string_arg = match special_arguments {
-        Some(args) if args.any_set() => {
-            format!("{} {} {}", args, lhs, rhs)
-        }
-        _ => {
-            format!("{} {}", lhs, rhs)
-        }
-    };
-
-    let cstring = CString::new(string_arg)?;
+    let cstring = CString::new(map_string(special_arguments, lhs, rhs))?;
     let mut arg = cstring.into_bytes_with_nul();
 
     let result = unsafe {
@@ -182,7 +221,7 @@ fn map(
             map_type as c_int,
             arg.as_mut_ptr(),
             State::from_str(mode)? as c_int,
-            false,
+            is_abbrev,
         )
     };
 
@@ -194,13 +233,65 @@ fn map(
         v => Err(Error::Unknown(v)),
     }
 }
+/// Builds the argument string for `do_map()` and `str_to_mapargs()`.
+///
+fn map_string(special_arguments: Option<SpecialArguments>, lhs: &str, rhs: &str) -> String {
+    let mut string_arg = match special_arguments {
+        Some(args) if args.any_set() => format!("{args} {lhs}"),
+        _ => lhs.to_string(),
+    };
+
+    // Unmapping doesn't take a rhs, and a trailing space would be taken as part of the lhs.
+    if !rhs.is_empty() {
+        string_arg.push(' ');
+        string_arg.push_str(rhs);
+    }
+
+    string_arg
+}
+
 /// # Errors
 ///
 /// This will error if a `Mapping` can't be built from any of the `Dictionary`s returned by the
 /// call to `nvim_get_keymap()`.
 ///
 pub fn get_maps(mode: &str) -> Result<Vec<Dictionary>, Error> {
-    let maps = unsafe { neovim_sys::api::nvim::nvim_get_keymap(NvimString::new(mode)?) };
+    let maps = unsafe { api::nvim::nvim_get_keymap(VIML_INTERNAL_CALL, NvimString::new(mode)?) };
+
+    into_dictionaries(maps)
+}
+
+/// Like `get_maps()`, but gets `buffer`'s buffer-local mappings; similar to
+/// `nvim_buf_get_keymap()`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode` can't be coerced to an `NvimString`.
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+/// - nvim returns something other than `Dictionary`s.
+///
+pub fn get_buf_maps(buffer: Buffer, mode: &str) -> Result<Vec<Dictionary>, Error> {
+    let mut out_err = LuaError::default();
+
+    let maps = unsafe {
+        api::buffer::nvim_buf_get_keymap(
+            VIML_INTERNAL_CALL,
+            buffer,
+            NvimString::new(mode)?,
+            &mut out_err,
+        )
+    };
+
+    if out_err.is_err() {
+        return Err(Error::from(out_err));
+    }
+
+    into_dictionaries(maps)
+}
+
+fn into_dictionaries(maps: Array) -> Result<Vec<Dictionary>, Error> {
     let mut output = Vec::with_capacity(maps.len());
 
     for object in maps {
@@ -227,7 +318,7 @@ pub fn set_buf_map(
     rhs: &str,
     options: Option<SpecialArguments>,
 ) -> Result<(), Error> {
-    buf_map(MapType::Map, buffer, mode, lhs, rhs, options)
+    buf_map(MapType::Map, buffer, mode, lhs, rhs, options, false)
 }
 
 /// Similar to `nvim_buf_set_keymap()`, but passing `{noremap = true}` with the options.
@@ -247,7 +338,70 @@ pub fn set_buf_noremap(
     rhs: &str,
     options: Option<SpecialArguments>,
 ) -> Result<(), Error> {
-    buf_map(MapType::NoRemap, buffer, mode, lhs, rhs, options)
+    buf_map(MapType::NoRemap, buffer, mode, lhs, rhs, options, false)
+}
+
+/// Deletes `buffer`'s mapping for `lhs` in `mode`; similar to `nvim_buf_del_keymap()`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode` or `lhs` can't be coerced to an `NvimString`.
+/// - `buffer` has no mapping for `lhs` in `mode` (`Error::NoMatches`).
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+///
+pub fn del_buf_map(buffer: Buffer, mode: &str, lhs: &str) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+
+    unsafe {
+        api::buffer::nvim_buf_del_keymap(
+            LUA_INTERNAL_CALL,
+            buffer,
+            NvimString::new(mode)?,
+            NvimString::new(lhs)?,
+            &mut out_err,
+        );
+    }
+
+    if !out_err.is_err() {
+        return Ok(());
+    }
+
+    // E31: No such mapping
+    if out_err.msg().to_string_lossy().starts_with("E31:") {
+        Err(Error::NoMatches(lhs.to_string()))
+    } else {
+        Err(Error::from(out_err))
+    }
+}
+
+/// Deletes all of `buffer`'s mappings in `mode`; like `:mapclear <buffer>`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode` isn't a known mode.
+/// - `buffer` doesn't exist.
+///
+pub fn clear_buf_maps(buffer: Buffer, mode: &str) -> Result<(), Error> {
+    clear(buffer, mode, true, false)
+}
+
+fn clear(buffer: Buffer, mode: &str, local: bool, is_abbrev: bool) -> Result<(), Error> {
+    let mode = State::from_str(mode)? as c_int;
+
+    let mut out_err = LuaError::default();
+    let buf = unsafe { private::find_buffer_by_handle(buffer, &mut out_err) };
+
+    if out_err.is_err() {
+        return Err(Error::from(out_err));
+    }
+
+    unsafe { getchar::map_clear_int(buf, mode, local, is_abbrev) };
+
+    Ok(())
 }
 
 fn buf_map(
@@ -257,19 +411,18 @@ fn buf_map(
     lhs: &str,
     rhs: &str,
     options: Option<SpecialArguments>,
+    is_abbrev: bool,
 ) -> Result<(), Error> {
-    let is_unmap = false;
+    let is_unmap = matches!(map_type, MapType::Unmap);
     let mut map_args = MapArguments::new();
 
     {
-        let string_arg = options.map_or_else(
-            || format!("{} {}", lhs, rhs),
-            |o| format!("{} {} {}", o, lhs, rhs),
-        );
-        let cstring = CString::new(string_arg)?;
+        let cstring = CString::new(map_string(options, lhs, rhs))?;
         let args = cstring.into_bytes_with_nul();
 
-        unsafe { getchar::str_to_mapargs(args.as_ptr(), is_unmap, &mut map_args) };
+        if unsafe { getchar::str_to_mapargs(args.as_ptr(), is_unmap, &mut map_args) } != 0 {
+            return Err(Error::InvalidArguments(rhs.to_string()));
+        }
     };
 
     let mut out_err = LuaError::default();
@@ -284,7 +437,7 @@ fn buf_map(
             map_type as c_int,
             &map_args,
             State::from_str(mode)? as c_int,
-            is_unmap,
+            is_abbrev,
             buf,
         )
     };
@@ -297,3 +450,306 @@ fn buf_map(
         v => Err(Error::Unknown(v)),
     }
 }
+
+/// Defines a mapping for `mode` that maps `lhs` to `rhs`, like `set_map()` (or `set_noremap()`, if
+/// `noremap` is set), but through `nvim_set_keymap()`; that can also give the mapping a `desc`.
+///
+/// If `buffer` is given (or `special_arguments` has `buffer` set, for the current buffer), the
+/// mapping is local to that buffer, like with `nvim_buf_set_keymap()`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode`, `lhs`, `rhs` or `desc` can't be coerced to an `NvimString`.
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+///
+pub fn set_keymap(
+    buffer: Option<Buffer>,
+    mode: &str,
+    lhs: &str,
+    rhs: &str,
+    noremap: bool,
+    special_arguments: Option<SpecialArguments>,
+    desc: Option<&str>,
+) -> Result<(), Error> {
+    let mut opts = keymap_opts(special_arguments, desc)?;
+    opts.noremap = Object::from(noremap);
+
+    set_keymap_with_opts(
+        buffer_for(buffer, special_arguments),
+        mode,
+        lhs,
+        rhs,
+        &mut opts,
+    )
+}
+
+/// Defines a mapping for `mode` that calls `callback` when `lhs` is typed, instead of mapping
+/// `lhs` to a `rhs`. If `special_arguments` has `buffer` set, the mapping is local to the current
+/// buffer.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode`, `lhs` or `desc` can't be coerced to an `NvimString`.
+/// - nvim returns an error.
+///
+pub fn set_map_fn(
+    mode: &str,
+    lhs: &str,
+    callback: Callback,
+    special_arguments: Option<SpecialArguments>,
+    desc: Option<&str>,
+) -> Result<(), Error> {
+    set_keymap_fn(
+        buffer_for(None, special_arguments),
+        mode,
+        lhs,
+        callback,
+        special_arguments,
+        desc,
+    )
+}
+
+/// Like `set_map_fn()`, but for a mapping that's local to `buffer`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode`, `lhs` or `desc` can't be coerced to an `NvimString`.
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+///
+pub fn set_buf_map_fn(
+    buffer: Buffer,
+    mode: &str,
+    lhs: &str,
+    callback: Callback,
+    special_arguments: Option<SpecialArguments>,
+    desc: Option<&str>,
+) -> Result<(), Error> {
+    set_keymap_fn(Some(buffer), mode, lhs, callback, special_arguments, desc)
+}
+
+/// `buffer`, or the current buffer (`Buffer::CURRENT`) if `special_arguments` has `buffer` set.
+///
+fn buffer_for(
+    buffer: Option<Buffer>,
+    special_arguments: Option<SpecialArguments>,
+) -> Option<Buffer> {
+    buffer.or_else(|| {
+        special_arguments
+            .filter(SpecialArguments::is_buffer)
+            .map(|_| Buffer::CURRENT)
+    })
+}
+
+fn keymap_opts(
+    special_arguments: Option<SpecialArguments>,
+    desc: Option<&str>,
+) -> Result<KeyDict_keymap, Error> {
+    let args = special_arguments.unwrap_or_default();
+
+    Ok(KeyDict_keymap {
+        nowait: Object::from(args.is_nowait()),
+        silent: Object::from(args.is_silent()),
+        script: Object::from(args.is_script()),
+        expr: Object::from(args.is_expr()),
+        unique: Object::from(args.is_unique()),
+        desc: match desc {
+            Some(desc) => Object::from(NvimString::new(desc)?),
+            None => Object::new_nil(),
+        },
+        ..KeyDict_keymap::default()
+    })
+}
+
+fn set_keymap_fn(
+    buffer: Option<Buffer>,
+    mode: &str,
+    lhs: &str,
+    callback: Callback,
+    special_arguments: Option<SpecialArguments>,
+    desc: Option<&str>,
+) -> Result<(), Error> {
+    let mut opts = keymap_opts(special_arguments, desc)?;
+
+    // `opts` owns the reference now. When nvim keeps the callback, it takes the reference out of
+    // `opts`; otherwise dropping `opts` releases it.
+    opts.callback = Object::new_luaref(callback.into_luaref());
+
+    set_keymap_with_opts(buffer, mode, lhs, "", &mut opts)
+}
+
+fn set_keymap_with_opts(
+    buffer: Option<Buffer>,
+    mode: &str,
+    lhs: &str,
+    rhs: &str,
+    opts: &mut KeyDict_keymap,
+) -> Result<(), Error> {
+    let mode = NvimString::new(mode)?;
+    let lhs = NvimString::new(lhs)?;
+    let rhs = NvimString::new(rhs)?;
+    let mut out_err = LuaError::default();
+
+    unsafe {
+        match buffer {
+            Some(buffer) => api::buffer::nvim_buf_set_keymap(
+                LUA_INTERNAL_CALL,
+                buffer,
+                mode,
+                lhs,
+                rhs,
+                opts,
+                &mut out_err,
+            ),
+            None => {
+                api::nvim::nvim_set_keymap(LUA_INTERNAL_CALL, mode, lhs, rhs, opts, &mut out_err);
+            }
+        }
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Defines an abbreviation for `mode` (one of "i", "c" or "!") that expands `lhs` to `rhs`; like
+/// `:abbreviate`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` and `rhs` can't be coerced to a `CString`.
+/// - nvim returns an error.
+///
+pub fn set_abbrev(
+    mode: &str,
+    lhs: &str,
+    rhs: &str,
+    special_arguments: Option<SpecialArguments>,
+) -> Result<(), Error> {
+    map(MapType::Map, mode, lhs, rhs, special_arguments, true)
+}
+
+/// Like `set_abbrev()`, but `rhs` isn't remapped; like `:noreabbrev`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` and `rhs` can't be coerced to a `CString`.
+/// - nvim returns an error.
+///
+pub fn set_noreabbrev(
+    mode: &str,
+    lhs: &str,
+    rhs: &str,
+    special_arguments: Option<SpecialArguments>,
+) -> Result<(), Error> {
+    map(MapType::NoRemap, mode, lhs, rhs, special_arguments, true)
+}
+
+/// Deletes the abbreviation for `lhs` in `mode`; like `:unabbreviate`. Only `buffer` is used from
+/// `special_arguments`, to delete the current buffer's abbreviation.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` can't be coerced to a `CString`.
+/// - There's no abbreviation for `lhs` in `mode` (`Error::NoMatches`).
+///
+pub fn del_abbrev(
+    mode: &str,
+    lhs: &str,
+    special_arguments: Option<SpecialArguments>,
+) -> Result<(), Error> {
+    let special_arguments = special_arguments
+        .filter(SpecialArguments::is_buffer)
+        .map(|_| SpecialArguments::default().buffer());
+
+    map(MapType::Unmap, mode, lhs, "", special_arguments, true)
+}
+
+/// Deletes all abbreviations in `mode`; like `:abclear`. Buffer-local abbreviations are left
+/// alone.
+///
+/// # Errors
+///
+/// This will error if `mode` isn't a known mode.
+///
+pub fn clear_abbrevs(mode: &str) -> Result<(), Error> {
+    clear(Buffer::CURRENT, mode, false, true)
+}
+
+/// Like `set_abbrev()`, but for an abbreviation that's local to `buffer`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` and `rhs` can't be coerced to a `CString`.
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+///
+pub fn set_buf_abbrev(
+    buffer: Buffer,
+    mode: &str,
+    lhs: &str,
+    rhs: &str,
+    options: Option<SpecialArguments>,
+) -> Result<(), Error> {
+    buf_map(MapType::Map, buffer, mode, lhs, rhs, options, true)
+}
+
+/// Like `set_noreabbrev()`, but for an abbreviation that's local to `buffer`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` and `rhs` can't be coerced to a `CString`.
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+///
+pub fn set_buf_noreabbrev(
+    buffer: Buffer,
+    mode: &str,
+    lhs: &str,
+    rhs: &str,
+    options: Option<SpecialArguments>,
+) -> Result<(), Error> {
+    buf_map(MapType::NoRemap, buffer, mode, lhs, rhs, options, true)
+}
+
+/// Deletes `buffer`'s abbreviation for `lhs` in `mode`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `lhs` can't be coerced to a `CString`.
+/// - `buffer` has no abbreviation for `lhs` in `mode` (`Error::NoMatches`).
+/// - nvim returns an error (ex. `buffer` doesn't exist).
+///
+pub fn del_buf_abbrev(buffer: Buffer, mode: &str, lhs: &str) -> Result<(), Error> {
+    buf_map(MapType::Unmap, buffer, mode, lhs, "", None, true)
+}
+
+/// Deletes all of `buffer`'s abbreviations in `mode`; like `:abclear <buffer>`.
+///
+/// # Errors
+///
+/// This will error if:
+///
+/// - `mode` isn't a known mode.
+/// - `buffer` doesn't exist.
+///
+pub fn clear_buf_abbrevs(buffer: Buffer, mode: &str) -> Result<(), Error> {
+    clear(buffer, mode, true, true)
+}
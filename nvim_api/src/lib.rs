@@ -26,14 +26,23 @@ pub use neovim_sys as sys;
 pub mod lua_test;
 
 pub mod buffer;
+pub mod callback;
+pub mod extmark;
 pub mod keymap;
 pub mod nvim;
+pub mod tabpage;
+pub mod window;
 
 pub(crate) mod error;
 pub(crate) mod rust_object;
 
-pub use self::{error::Error, rust_object::RustObject};
+pub use self::{
+    error::{Error, VimException},
+    rust_object::RustObject,
+};
 pub use neovim_sys::api::{
     buffer::Buffer,
     nvim::{Array, Boolean, Dictionary, Float, Integer, LuaRef, NvimString, Object},
+    tabpage::Tabpage,
+    window::Window,
 };
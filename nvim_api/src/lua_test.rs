@@ -1,6 +1,6 @@
 #![allow(missing_docs, clippy::missing_panics_doc)]
 
-use crate::{Object, RustObject};
+use crate::{Buffer, Object, RustObject, Tabpage, Window};
 use neovim_sys::api::nvim::{Array, Dictionary, KeyValuePair, NvimString, ObjectType};
 use nvim_api_test::nvim_test;
 use std::borrow::Borrow;
@@ -8,7 +8,9 @@ use std::borrow::Borrow;
 fn _test_nvim_setget_var(var: &str, value: Object, expected_object_variant: &RustObject) {
     crate::nvim::nvim_set_var(var, value).unwrap();
 
-    let t = crate::nvim::nvim_get_var(var).map(RustObject::from).unwrap();
+    let t = crate::nvim::nvim_get_var(var)
+        .map(RustObject::from)
+        .unwrap();
 
     assert_eq!(
         t, *expected_object_variant,
@@ -114,9 +116,9 @@ fn test_nvim_buf_set_var() {
     let value = Object::from(make_subject());
     let var = "nvim_rs_buf_set_get_var";
 
-    crate::buffer::nvim_buf_set_var(0, var, value).unwrap();
+    crate::buffer::nvim_buf_set_var(Buffer::CURRENT, var, value).unwrap();
 
-    let object = crate::buffer::nvim_buf_get_var(0, var).unwrap();
+    let object = crate::buffer::nvim_buf_get_var(Buffer::CURRENT, var).unwrap();
     assert_eq!(object.object_type(), ObjectType::kObjectTypeDictionary);
 
     let dict = object.into_dictionary_unchecked();
@@ -125,7 +127,7 @@ fn test_nvim_buf_set_var() {
 
 #[nvim_test]
 fn test_nvim_get_current_buf() {
-    assert_eq!(crate::nvim::nvim_get_current_buf(), 1);
+    assert_eq!(crate::nvim::nvim_get_current_buf(), Buffer::new(1));
 }
 
 #[nvim_test]
@@ -133,6 +135,18 @@ fn test_nvim_feedkeys() {
     crate::nvim::nvim_feedkeys("j", "n", false).unwrap();
 }
 
+#[nvim_test]
+fn test_nvim_replace_termcodes() {
+    let replaced = crate::nvim::nvim_replace_termcodes("<CR>x<lt>", true, true, true).unwrap();
+    assert_eq!(replaced.to_bytes(), b"\rx<");
+
+    let replaced = crate::nvim::nvim_replace_termcodes("<CR>", true, true, false).unwrap();
+    assert_eq!(replaced.to_bytes(), b"<CR>");
+
+    let replaced = crate::nvim::nvim_replace_termcodes("", true, true, true).unwrap();
+    assert!(replaced.to_bytes().is_empty());
+}
+
 #[nvim_test]
 fn test_nvim_get_mode() {
     let current_mode = crate::nvim::nvim_get_mode();
@@ -247,7 +261,7 @@ fn test_set_buf_map() {
         .unique();
 
     let result = crate::keymap::set_buf_map(
-        0, // current buffer
+        Buffer::CURRENT,
         "n",
         "<C-8>",
         "<cmd>echo \"bye\"",
@@ -279,7 +293,7 @@ fn test_set_buf_noremap() {
         .unique();
 
     let result = crate::keymap::set_buf_noremap(
-        0, // current buffer
+        Buffer::CURRENT,
         "n",
         "<C-2>",
         "<cmd>echo \"tacos\"",
@@ -302,3 +316,425 @@ fn test_set_buf_noremap() {
         &NvimString::new_unchecked("<Cmd>echo \"tacos\"")
     );
 }
+
+#[nvim_test]
+fn test_nvim_get_current_win() {
+    let window = crate::nvim::nvim_get_current_win();
+
+    assert_eq!(window.handle(), 1000);
+    assert_eq!(crate::nvim::nvim_list_wins(), vec![window]);
+    assert_eq!(
+        crate::window::nvim_win_get_buf(window).unwrap(),
+        Buffer::new(1)
+    );
+    assert!(crate::window::nvim_win_get_width(window).unwrap() > 0);
+    assert!(crate::window::nvim_win_get_height(window).unwrap() > 0);
+}
+
+#[nvim_test]
+fn test_nvim_win_set_cursor() {
+    crate::window::nvim_win_set_cursor(Window::CURRENT, (1, 0)).unwrap();
+
+    assert_eq!(
+        crate::window::nvim_win_get_cursor(Window::CURRENT).unwrap(),
+        (1, 0)
+    );
+
+    // Out of range
+    assert!(crate::window::nvim_win_set_cursor(Window::CURRENT, (42, 0)).is_err());
+}
+
+#[nvim_test]
+fn test_nvim_win_set_var() {
+    let var = "nvim_rs_win_set_get_var";
+
+    crate::window::nvim_win_set_var(Window::CURRENT, var, Object::from(42)).unwrap();
+
+    let object = crate::window::nvim_win_get_var(Window::CURRENT, var).unwrap();
+    assert_eq!(RustObject::from(object), RustObject::Integer(42));
+}
+
+#[nvim_test]
+fn test_nvim_get_current_tabpage() {
+    let tabpage = crate::nvim::nvim_get_current_tabpage();
+
+    assert_eq!(crate::nvim::nvim_list_tabpages(), vec![tabpage]);
+    assert!(crate::tabpage::nvim_tabpage_is_valid(tabpage));
+    assert!(!crate::tabpage::nvim_tabpage_is_valid(Tabpage::new(
+        tabpage.handle() + 42
+    )));
+    assert_eq!(crate::tabpage::nvim_tabpage_get_number(tabpage).unwrap(), 1);
+
+    let window = crate::tabpage::nvim_tabpage_get_win(tabpage).unwrap();
+    assert_eq!(window, crate::nvim::nvim_get_current_win());
+    assert_eq!(
+        crate::tabpage::nvim_tabpage_list_wins(tabpage).unwrap(),
+        vec![window]
+    );
+}
+
+#[nvim_test]
+fn test_nvim_tabpage_set_var() {
+    let var = "nvim_rs_tabpage_set_get_var";
+
+    crate::tabpage::nvim_tabpage_set_var(Tabpage::CURRENT, var, Object::from(true)).unwrap();
+
+    let object = crate::tabpage::nvim_tabpage_get_var(Tabpage::CURRENT, var).unwrap();
+    assert_eq!(RustObject::from(object), RustObject::Boolean(true));
+
+    crate::tabpage::nvim_tabpage_del_var(Tabpage::CURRENT, var).unwrap();
+    assert!(crate::tabpage::nvim_tabpage_get_var(Tabpage::CURRENT, var).is_err());
+}
+
+#[nvim_test]
+fn test_nvim_buf_set_lines() {
+    crate::buffer::nvim_buf_set_lines(Buffer::CURRENT, .., false, &["one", "two", "three", "four"])
+        .unwrap();
+    assert_eq!(
+        crate::buffer::nvim_buf_line_count(Buffer::CURRENT).unwrap(),
+        4
+    );
+
+    assert_eq!(
+        crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, .., true).unwrap(),
+        vec!["one", "two", "three", "four"]
+    );
+    assert_eq!(
+        crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, 1..=2, true).unwrap(),
+        vec!["two", "three"]
+    );
+    assert_eq!(
+        crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, -2.., true).unwrap(),
+        vec!["three", "four"]
+    );
+    assert_eq!(
+        crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, ..-1, true).unwrap(),
+        vec!["one", "two", "three"]
+    );
+    assert!(crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, 10..12, true).is_err());
+
+    // Insert
+    crate::buffer::nvim_buf_set_lines(Buffer::CURRENT, 1..1, true, &["one and a half"]).unwrap();
+    assert_eq!(
+        crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, ..3, true).unwrap(),
+        vec!["one", "one and a half", "two"]
+    );
+
+    // Delete
+    crate::buffer::nvim_buf_set_lines::<_, &str>(Buffer::CURRENT, 1..2, true, &[]).unwrap();
+    assert_eq!(
+        crate::buffer::nvim_buf_line_count(Buffer::CURRENT).unwrap(),
+        4
+    );
+
+    // Newlines aren't allowed
+    assert!(crate::buffer::nvim_buf_set_lines(Buffer::CURRENT, .., true, &["a\nb"]).is_err());
+}
+
+#[nvim_test]
+fn test_nvim_buf_set_text() {
+    crate::buffer::nvim_buf_set_lines(Buffer::CURRENT, .., false, &["hello world", "meow"])
+        .unwrap();
+    crate::buffer::nvim_buf_set_text(Buffer::CURRENT, (0, 6)..(1, 2), &["there", "new li"])
+        .unwrap();
+
+    assert_eq!(
+        crate::buffer::nvim_buf_get_lines(Buffer::CURRENT, .., true).unwrap(),
+        vec!["hello there", "new liow"]
+    );
+}
+
+#[nvim_test]
+fn test_nvim_create_buf() {
+    let buffer = crate::nvim::nvim_create_buf(true, false).unwrap();
+
+    assert!(crate::nvim::nvim_list_bufs().contains(&buffer));
+    assert!(crate::buffer::nvim_buf_is_valid(buffer));
+    assert!(crate::buffer::nvim_buf_is_loaded(buffer));
+
+    // Name
+    assert!(crate::buffer::nvim_buf_get_name(buffer).unwrap().is_empty());
+    crate::buffer::nvim_buf_set_name(buffer, "/tmp/nvim_rs_test_buffer").unwrap();
+    assert_eq!(
+        crate::buffer::nvim_buf_get_name(buffer).unwrap(),
+        NvimString::new_unchecked("/tmp/nvim_rs_test_buffer")
+    );
+
+    // changedtick
+    let tick = crate::buffer::nvim_buf_get_changedtick(buffer).unwrap();
+    crate::buffer::nvim_buf_set_lines(buffer, .., true, &["meow"]).unwrap();
+    assert!(crate::buffer::nvim_buf_get_changedtick(buffer).unwrap() > tick);
+
+    // Delete; fails without `force` since it's been modified.
+    assert!(crate::buffer::nvim_buf_delete(buffer, false, false).is_err());
+    crate::buffer::nvim_buf_delete(buffer, true, false).unwrap();
+    assert!(!crate::buffer::nvim_buf_is_valid(buffer));
+}
+
+#[nvim_test]
+fn test_nvim_create_namespace() {
+    let ns_id = crate::nvim::nvim_create_namespace("nvim_rs_test_namespace").unwrap();
+
+    assert_eq!(
+        crate::nvim::nvim_create_namespace("nvim_rs_test_namespace").unwrap(),
+        ns_id
+    );
+    assert_eq!(
+        crate::nvim::nvim_get_namespaces().get_as_integer("nvim_rs_test_namespace"),
+        Some(ns_id)
+    );
+}
+
+#[nvim_test]
+fn test_nvim_buf_set_extmark() {
+    use crate::extmark::{ExtmarkOpts, ExtmarkPosition, VirtTextPos};
+
+    let ns_id = crate::nvim::nvim_create_namespace("nvim_rs_test_extmarks").unwrap();
+    crate::buffer::nvim_buf_set_lines(Buffer::CURRENT, .., false, &["one", "two", "three"])
+        .unwrap();
+
+    let opts = ExtmarkOpts::default()
+        .end_row(1)
+        .end_col(2)
+        .hl_group("Search")
+        .virt_text(&[("meow", Some("Comment"))])
+        .virt_text_pos(VirtTextPos::Eol)
+        .priority(200);
+    let id = crate::buffer::nvim_buf_set_extmark(Buffer::CURRENT, ns_id, 0, 1, opts).unwrap();
+    crate::buffer::nvim_buf_set_extmark(Buffer::CURRENT, ns_id, 2, 0, ExtmarkOpts::default())
+        .unwrap();
+
+    let extmarks = crate::buffer::nvim_buf_get_extmarks(
+        Buffer::CURRENT,
+        ns_id,
+        ExtmarkPosition::START,
+        ExtmarkPosition::END,
+        None,
+        true,
+    )
+    .unwrap();
+    assert_eq!(extmarks.len(), 2);
+    assert_eq!(extmarks[0].id(), id);
+    assert_eq!((extmarks[0].row(), extmarks[0].col()), (0, 1));
+
+    let details = extmarks[0].details().unwrap();
+    assert_eq!(details.get_as_integer("end_row"), Some(1));
+    assert_eq!(details.get_as_integer("priority"), Some(200));
+
+    // Limit
+    let extmarks = crate::buffer::nvim_buf_get_extmarks(
+        Buffer::CURRENT,
+        ns_id,
+        ExtmarkPosition::START,
+        ExtmarkPosition::END,
+        Some(1),
+        false,
+    )
+    .unwrap();
+    assert_eq!(extmarks.len(), 1);
+    assert!(extmarks[0].details().is_none());
+
+    // Delete
+    assert!(crate::buffer::nvim_buf_del_extmark(Buffer::CURRENT, ns_id, id).unwrap());
+    assert!(!crate::buffer::nvim_buf_del_extmark(Buffer::CURRENT, ns_id, id).unwrap());
+
+    // Clear
+    crate::buffer::nvim_buf_clear_namespace(Buffer::CURRENT, ns_id, ..).unwrap();
+    let extmarks = crate::buffer::nvim_buf_get_extmarks(
+        Buffer::CURRENT,
+        ns_id,
+        ExtmarkPosition::START,
+        ExtmarkPosition::END,
+        None,
+        false,
+    )
+    .unwrap();
+    assert!(extmarks.is_empty());
+}
+
+#[nvim_test]
+fn test_nvim_command() {
+    crate::nvim::nvim_command("let g:nvim_rs_test_command = 42").unwrap();
+
+    assert_eq!(
+        crate::nvim::nvim_get_var("nvim_rs_test_command")
+            .unwrap()
+            .try_as_integer()
+            .unwrap(),
+        42
+    );
+    assert!(crate::nvim::nvim_command("NotARealCommand").is_err());
+}
+
+#[nvim_test]
+fn test_nvim_get_hl_by_name() {
+    let mut val = Dictionary::new();
+    val.set(NvimString::new_unchecked("fg"), 0x00c0_ffee);
+    val.set(NvimString::new_unchecked("ctermfg"), 123);
+    val.set(NvimString::new_unchecked("bold"), true);
+    crate::nvim::nvim_set_hl(0, "NvimRsTestGetHl", val).unwrap();
+
+    let rgb = crate::nvim::nvim_get_hl_by_name("NvimRsTestGetHl", true).unwrap();
+    assert_eq!(rgb.get_as_integer("foreground"), Some(0x00c0_ffee));
+    assert_eq!(rgb.get_as_boolean("bold"), Some(true));
+
+    let cterm = crate::nvim::nvim_get_hl_by_name("NvimRsTestGetHl", false).unwrap();
+    assert_eq!(cterm.get_as_integer("foreground"), Some(123));
+
+    let hl_id = crate::nvim::nvim_get_hl_id_by_name("NvimRsTestGetHl").unwrap();
+    assert_eq!(crate::nvim::nvim_get_hl_by_id(hl_id, true).unwrap(), rgb);
+
+    assert!(crate::nvim::nvim_get_hl_by_name("NvimRsTestNotAGroup", true).is_err());
+}
+
+#[nvim_test]
+fn test_nvim_exec() {
+    assert_eq!(
+        crate::nvim::nvim_exec("echo 'meow'", true).unwrap(),
+        Some("meow".to_string())
+    );
+    let src = "let g:nvim_rs_test_exec = 1\nlet g:nvim_rs_test_exec += 1";
+    assert_eq!(crate::nvim::nvim_exec(src, false).unwrap(), None);
+    assert_eq!(
+        crate::nvim::nvim_get_var("nvim_rs_test_exec")
+            .unwrap()
+            .try_as_integer()
+            .unwrap(),
+        2
+    );
+
+    match crate::nvim::nvim_exec("NotARealCommand", false) {
+        Err(crate::Error::VimException(e)) => assert_eq!(e.code(), Some(492)),
+        other => panic!("Expected a VimException, got {other:?}"),
+    }
+
+    match crate::nvim::nvim_exec("throw 'meow'", false) {
+        Err(crate::Error::VimException(e)) => assert_eq!(e.message(), "meow"),
+        other => panic!("Expected a VimException, got {other:?}"),
+    }
+}
+
+#[nvim_test]
+fn test_nvim_call_function() {
+    let args = Array::new_from([Object::from(NvimString::new_unchecked("meow"))]);
+    assert_eq!(
+        crate::nvim::nvim_call_function("toupper", args).unwrap(),
+        Object::from(NvimString::new_unchecked("MEOW"))
+    );
+
+    match crate::nvim::nvim_call_function("NotARealFunction", Array::new()) {
+        Err(crate::Error::VimException(e)) => assert_eq!(e.code(), Some(117)),
+        other => panic!("Expected a VimException, got {other:?}"),
+    }
+
+    crate::nvim::nvim_exec(
+        "let g:nvim_rs_test_dict = {'value': 42}\n\
+        function! g:nvim_rs_test_dict.get() dict\nreturn self.value\nendfunction",
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        crate::nvim::nvim_call_dict_function(
+            Object::from(NvimString::new_unchecked("g:nvim_rs_test_dict")),
+            "get",
+            Array::new()
+        )
+        .unwrap(),
+        Object::from(42)
+    );
+
+    assert_eq!(crate::nvim::nvim_eval("1 + 2").unwrap(), Object::from(3));
+    assert!(crate::nvim::nvim_eval("1 +").is_err());
+}
+
+#[nvim_test]
+fn test_nvim_exec_lua() {
+    let args = Array::new_from([Object::from(1), Object::from(2)]);
+    assert_eq!(
+        crate::nvim::nvim_exec_lua("local a, b = ...; return a + b", args).unwrap(),
+        Object::from(3)
+    );
+    assert!(crate::nvim::nvim_exec_lua("return", Array::new())
+        .unwrap()
+        .is_nil());
+    assert!(crate::nvim::nvim_exec_lua("error('meow')", Array::new()).is_err());
+    assert!(crate::nvim::nvim_exec_lua("not lua", Array::new()).is_err());
+}
+
+#[nvim_test]
+fn test_callback() {
+    use crate::callback::Callback;
+    use std::{cell::Cell, rc::Rc};
+
+    let calls = Rc::new(Cell::new(0));
+    let callback_calls = Rc::clone(&calls);
+
+    let callback = Callback::new(move |args: Array| {
+        callback_calls.set(callback_calls.get() + 1);
+
+        match args.first().map(Object::try_as_integer) {
+            Some(Ok(i)) => Ok(Object::from(i * 2)),
+            _ => Err(crate::Error::Raw("meow".to_string())),
+        }
+    })
+    .unwrap();
+
+    assert_eq!(
+        callback.call(Array::new_from([Object::from(21)])).unwrap(),
+        Object::from(42)
+    );
+
+    // Errors from the closure become Lua errors.
+    let src = "local ref = ...; local ok, err = pcall(debug.getregistry()[ref]); return err";
+    let args = Array::new_from([Object::from(i64::from(callback.luaref()))]);
+    let message = crate::nvim::nvim_exec_lua(src, args)
+        .unwrap()
+        .try_into_string()
+        .unwrap();
+    assert!(message.to_string_lossy().contains("meow"));
+    assert_eq!(calls.get(), 2);
+
+    // ...as do panics.
+    let panicking = Callback::new(|_| panic!("woof")).unwrap();
+    assert!(panicking.call(Array::new()).is_err());
+
+    // Once the Lua function is collected, so is the closure.
+    drop(callback);
+    crate::nvim::nvim_exec_lua("collectgarbage()", Array::new()).unwrap();
+    assert_eq!(Rc::strong_count(&calls), 1);
+}
+
+#[nvim_test]
+fn test_luaref_object() {
+    use crate::callback::Callback;
+    use std::rc::Rc;
+
+    let alive = Rc::new(());
+    let callback_alive = Rc::clone(&alive);
+    let callback = Callback::new(move |_| {
+        let _ = &callback_alive;
+        Ok(Object::from(7))
+    })
+    .unwrap();
+
+    // Each `Object` gets its own reference.
+    let object = Object::from(&callback);
+    let clone = object.clone();
+    assert_ne!(object.as_luaref_unchecked(), callback.luaref());
+    assert_ne!(clone.as_luaref_unchecked(), object.as_luaref_unchecked());
+
+    drop(callback);
+    drop(object);
+
+    let src = "local ref = ...; return debug.getregistry()[ref]()";
+    let args = Array::new_from([Object::from(i64::from(clone.as_luaref_unchecked()))]);
+    assert_eq!(
+        crate::nvim::nvim_exec_lua(src, args).unwrap(),
+        Object::from(7)
+    );
+
+    // Dropping the last reference lets the function (and so the closure) be collected.
+    drop(clone);
+    crate::nvim::nvim_exec_lua("collectgarbage()", Array::new()).unwrap();
+    assert_eq!(Rc::strong_count(&alive), 1);
+}
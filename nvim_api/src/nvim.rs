@@ -2,10 +2,10 @@
 //! This module contains function wrappers for neovim functions defined in
 //! `neovim/src/nvim/api/vim.c`.
 //!
-use super::{Buffer, Error};
+use super::{Buffer, Error, Integer, Tabpage, VimException, Window};
 use neovim_sys::{
     api::{
-        nvim::{self, Dictionary, LuaError, NvimString, Object, ObjectType},
+        nvim::{self, Array, Dictionary, ErrorType, LuaError, NvimString, Object, ObjectType},
         private,
     },
     option::{self, OptionFlags, SOpt, SReq},
@@ -280,11 +280,22 @@ where
 /// * If nvim set an error to `v:errmsg`.
 ///
 pub fn nvim_feedkeys(keys: &str, mode: &str, escape_csi: bool) -> Result<(), Error> {
-    let api_keys = NvimString::new(keys)?;
+    nvim_feedkeys_replaced(NvimString::new(keys)?, mode, escape_csi)
+}
+
+/// Like `nvim_feedkeys()`, but for `keys` that came from `nvim_replace_termcodes()`, which aren't
+/// necessarily UTF-8.
+///
+/// # Errors
+///
+/// * If `mode` can't be converted to a `NvimString`.
+/// * If nvim set an error to `v:errmsg`.
+///
+pub fn nvim_feedkeys_replaced(keys: NvimString, mode: &str, escape_csi: bool) -> Result<(), Error> {
     let api_mode = NvimString::new(mode)?;
 
     unsafe {
-        nvim::nvim_feedkeys(api_keys, api_mode, escape_csi);
+        nvim::nvim_feedkeys(keys, api_mode, escape_csi);
     }
 
     match nvim_get_vvar("errmsg") {
@@ -306,6 +317,28 @@ pub fn nvim_feedkeys(keys: &str, mode: &str, escape_csi: bool) -> Result<(), Err
     }
 }
 
+/// Executes an Ex command, like `:highlight clear`.
+///
+/// # Errors
+///
+/// * If `command` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. the command failed).
+///
+pub fn nvim_command(command: &str) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let api_command = NvimString::new(command)?;
+
+    unsafe {
+        nvim::nvim_command(api_command, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
 /// Gets the current buffer (number).
 ///
 #[must_use]
@@ -313,6 +346,66 @@ pub fn nvim_get_current_buf() -> Buffer {
     unsafe { nvim::nvim_get_current_buf() }
 }
 
+/// Gets the current list of buffer handles, including unlisted (unloaded/deleted) buffers.
+///
+#[must_use]
+pub fn nvim_list_bufs() -> Vec<Buffer> {
+    let buffers = unsafe { nvim::nvim_list_bufs() };
+
+    buffers.iter().map(Object::as_buffer_unchecked).collect()
+}
+
+/// Creates a new, empty, unnamed buffer. A `scratch` buffer is a "throwaway" buffer (see
+/// `:h scratch-buffer`).
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_create_buf(listed: bool, scratch: bool) -> Result<Buffer, Error> {
+    let mut out_err = LuaError::default();
+
+    let buffer = unsafe { nvim::nvim_create_buf(listed, scratch, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(buffer)
+    }
+}
+
+/// Gets the current list of window handles.
+///
+#[must_use]
+pub fn nvim_list_wins() -> Vec<Window> {
+    let windows = unsafe { nvim::nvim_list_wins() };
+
+    windows.iter().map(Object::as_window_unchecked).collect()
+}
+
+/// Gets the current window handle.
+///
+#[must_use]
+pub fn nvim_get_current_win() -> Window {
+    unsafe { nvim::nvim_get_current_win() }
+}
+
+/// Gets the current list of tabpage handles.
+///
+#[must_use]
+pub fn nvim_list_tabpages() -> Vec<Tabpage> {
+    let tabpages = unsafe { nvim::nvim_list_tabpages() };
+
+    tabpages.iter().map(Object::as_tabpage_unchecked).collect()
+}
+
+/// Gets the current tabpage handle.
+///
+#[must_use]
+pub fn nvim_get_current_tabpage() -> Tabpage {
+    unsafe { nvim::nvim_get_current_tabpage() }
+}
+
 /// The `Dictionary` returned from nvim  contains both a `mode` and `blocking` key.
 ///
 #[must_use]
@@ -320,59 +413,268 @@ pub fn nvim_get_mode() -> Dictionary {
     unsafe { nvim::nvim_get_mode() }
 }
 
-//pub fn nvim_replace_termcodes(
-//    string_to_convert: &str,
-//    from_part: bool,
-//    do_lt: bool,
-//    special: bool,
-//) -> String {
-//    unsafe {
-//        let api_string = cstr_to_string(string_to_convert.as_ptr() as *const c_char);
-
-//        String::new(Cow::Owned(vim::nvim_replace_termcodes(
-//            api_string, from_part, do_lt, special,
-//        )))
-//    }
-//}
-
-//pub fn nvim_exec(src: &str, output: bool) -> Result<String, Error> {
-//    unsafe {
-//        let api_src = cstr_to_string(src.as_ptr() as *const c_char);
-//        let mut out_err = Error::default();
-
-//        let vim_string = vim::nvim_exec(api_src, output, out_err.inner_mut());
-
-//        if out_err.is_err() {
-//            Err(out_err)
-//        } else {
-//            Ok(String::new(Cow::Owned(vim_string)))
-//        }
-//    }
-//}
-
-//pub fn nvim_set_hl(namespace_id: Integer, name: &str, val: Dictionary) -> Result<(), Error> {
-//    unsafe {
-//        let api_name = cstr_to_string(name.as_ptr() as *const c_char);
-//        let mut out_err = Error::default();
-
-//        vim::nvim_set_hl(namespace_id, api_name, val.inner(), out_err.inner_mut());
-
-//        if out_err.is_err() {
-//            Err(out_err)
-//        } else {
-//            Ok(())
-//        }
-//    }
-//}
-
-//pub fn nvim_get_namespaces() -> Dictionary {
-//    unsafe { Dictionary::new(vim::nvim_get_namespaces()) }
-//}
-
-//pub fn nvim_create_namespace(name: &str) -> Integer {
-//    unsafe {
-//        let api_name = cstr_to_string(name.as_ptr() as *const c_char);
-
-//        nvim::nvim_create_namespace(api_name)
-//    }
-//}
+/// Replaces terminal codes and keycodes (ex. `<CR>`, `<Esc>`) in `s` with nvim's internal
+/// representation of them, which is what `nvim_feedkeys_replaced()` takes.
+///
+/// * `from_part`: Legacy Vim parameter; usually `true`.
+/// * `do_lt`: Also translate `<lt>`. Ignored if `special` is `false`.
+/// * `special`: Replace keycodes (ex. `<CR>` becomes `"\r"`).
+///
+/// An empty `s` gives an empty string; nvim's own result for that has a null `data` pointer.
+///
+/// # Errors
+///
+/// * If `s` can't be converted to a `NvimString`.
+///
+pub fn nvim_replace_termcodes(
+    s: &str,
+    from_part: bool,
+    do_lt: bool,
+    special: bool,
+) -> Result<NvimString, Error> {
+    if s.is_empty() {
+        return Ok(NvimString::new_unchecked(""));
+    }
+
+    let api_s = NvimString::new(s)?;
+
+    Ok(unsafe { nvim::nvim_replace_termcodes(api_s, from_part, do_lt, special) })
+}
+
+/// Executes Vimscript `src`, which can be multiple lines (like a sourced file). If
+/// `capture_output` is `true`, output that would've been displayed (ex. from `:echo`) is
+/// captured and returned instead.
+///
+/// # Errors
+///
+/// * If `src` can't be converted to a `NvimString`.
+/// * If the Vimscript throws an exception or has an error; this is returned as
+///   `Error::VimException`.
+///
+pub fn nvim_exec(src: &str, capture_output: bool) -> Result<Option<String>, Error> {
+    let mut out_err = LuaError::default();
+    let api_src = NvimString::new(src)?;
+
+    let output = unsafe { nvim::nvim_exec(api_src, capture_output, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(vim_error(out_err))
+    } else if capture_output {
+        // If nothing was output, nvim returns a string with no data at all (not even a nul byte).
+        if output.as_ptr().is_null() {
+            Ok(Some(String::new()))
+        } else {
+            Ok(Some(String::from(output)))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// Calls Vimscript function `fn_name` with `args`.
+///
+/// # Errors
+///
+/// * If `fn_name` can't be converted to a `NvimString`.
+/// * If the function throws an exception or has an error; this is returned as
+///   `Error::VimException`.
+///
+pub fn nvim_call_function(fn_name: &str, args: Array) -> Result<Object, Error> {
+    let mut out_err = LuaError::default();
+    let api_fn_name = NvimString::new(fn_name)?;
+
+    let object = unsafe { nvim::nvim_call_function(api_fn_name, args, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(vim_error(out_err))
+    } else {
+        Ok(object)
+    }
+}
+
+/// Calls Vimscript dictionary function `fn_name` on `dict`, with `dict` as `self`.
+///
+/// `fn_name` can be a `dict` function or a funcref stored on `dict`. `dict` can be a
+/// `Dictionary`, or a `String` expression that evaluates to one (ex. `"g:my_dict"`).
+///
+/// # Errors
+///
+/// * If `fn_name` can't be converted to a `NvimString`.
+/// * If the function throws an exception or has an error; this is returned as
+///   `Error::VimException`.
+///
+pub fn nvim_call_dict_function(dict: Object, fn_name: &str, args: Array) -> Result<Object, Error> {
+    let mut out_err = LuaError::default();
+    let api_fn_name = NvimString::new(fn_name)?;
+
+    let object = unsafe { nvim::nvim_call_dict_function(dict, api_fn_name, args, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(vim_error(out_err))
+    } else {
+        Ok(object)
+    }
+}
+
+/// Evaluates Vimscript expression `expr` (ex. `"&tabstop + 1"`).
+///
+/// # Errors
+///
+/// * If `expr` can't be converted to a `NvimString`.
+/// * If the expression is invalid or evaluating it fails; this is returned as
+///   `Error::VimException`.
+///
+pub fn nvim_eval(expr: &str) -> Result<Object, Error> {
+    let mut out_err = LuaError::default();
+    let api_expr = NvimString::new(expr)?;
+
+    let object = unsafe { nvim::nvim_eval(api_expr, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(vim_error(out_err))
+    } else {
+        Ok(object)
+    }
+}
+
+/// Executes Lua chunk `code`. `args` are available as `...` inside the chunk, and the value it
+/// `return`s (if any) gets converted to an `Object`.
+///
+/// ```no_run
+/// use nvim_api::{nvim, Array, Object};
+///
+/// let sum = nvim::nvim_exec_lua(
+///     "local a, b = ...; return a + b",
+///     Array::new_from([Object::from(1), Object::from(2)]),
+/// )
+/// .unwrap();
+/// ```
+///
+/// # Errors
+///
+/// * If `code` can't be converted to a `NvimString`.
+/// * If the chunk has a syntax error, or raises an error when it's run.
+///
+pub fn nvim_exec_lua(code: &str, args: Array) -> Result<Object, Error> {
+    let mut out_err = LuaError::default();
+    let api_code = NvimString::new(code)?;
+
+    let object = unsafe { nvim::nvim_exec_lua(api_code, args, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(object)
+    }
+}
+
+/// nvim reports Vimscript errors and exceptions as `kErrorTypeException`s, whose message is like
+/// `v:exception`; those get parsed into a `VimException`.
+///
+fn vim_error(out_err: LuaError) -> Error {
+    match out_err.error_type() {
+        ErrorType::kErrorTypeException => {
+            Error::VimException(VimException::from(out_err.msg().to_string_lossy().as_ref()))
+        }
+        _ => Error::from(out_err),
+    }
+}
+
+/// Sets a highlight group. `ns_id` is the namespace to set it in; `0` is the global namespace.
+/// `val` is the same kind of `Dictionary` that `nvim_get_hl_by_name()` returns (ex. `fg`,
+/// `bold`, `link`).
+///
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. `val` has an invalid key or color).
+///
+pub fn nvim_set_hl(ns_id: Integer, name: &str, val: Dictionary) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    unsafe {
+        nvim::nvim_set_hl(ns_id, api_name, val, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Gets the definition of highlight group `name`, with links followed.
+///
+/// When `rgb` is `true`, the `foreground`, `background` and `special` colors (and attributes) are
+/// the GUI ones, as `0xRRGGBB` integers; otherwise they're the cterm ones.
+///
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. there's no highlight group called `name`).
+///
+pub fn nvim_get_hl_by_name(name: &str, rgb: bool) -> Result<Dictionary, Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    let dict = unsafe { nvim::nvim_get_hl_by_name(api_name, rgb, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(dict)
+    }
+}
+
+/// Same as `nvim_get_hl_by_name()`, but gets the highlight group by its ID (see
+/// `nvim_get_hl_id_by_name()` and `synIDattr()`).
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. `hl_id` is invalid).
+///
+pub fn nvim_get_hl_by_id(hl_id: Integer, rgb: bool) -> Result<Dictionary, Error> {
+    let mut out_err = LuaError::default();
+
+    let dict = unsafe { nvim::nvim_get_hl_by_id(hl_id, rgb, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(dict)
+    }
+}
+
+/// Gets the ID of highlight group `name`. Like `hlID()`, this creates the group if it doesn't
+/// already exist.
+///
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+///
+pub fn nvim_get_hl_id_by_name(name: &str) -> Result<Integer, Error> {
+    let api_name = NvimString::new(name)?;
+
+    Ok(unsafe { nvim::nvim_get_hl_id_by_name(api_name) })
+}
+
+/// Gets existing, non-anonymous namespaces. The `Dictionary` maps names to namespace IDs.
+///
+#[must_use]
+pub fn nvim_get_namespaces() -> Dictionary {
+    unsafe { nvim::nvim_get_namespaces() }
+}
+
+/// Creates a new namespace or gets an existing one. Pass an empty `name` to create an anonymous
+/// namespace.
+///
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+///
+pub fn nvim_create_namespace(name: &str) -> Result<Integer, Error> {
+    let api_name = NvimString::new(name)?;
+
+    Ok(unsafe { nvim::nvim_create_namespace(api_name) })
+}
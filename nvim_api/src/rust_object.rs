@@ -1,5 +1,8 @@
-use neovim_sys::api::nvim::{
-    Array, Boolean, Dictionary, Float, Integer, NvimString, Object, ObjectType,
+use neovim_sys::api::{
+    buffer::Buffer,
+    nvim::{Array, Boolean, Dictionary, Float, Integer, LuaRef, NvimString, Object, ObjectType},
+    tabpage::Tabpage,
+    window::Window,
 };
 
 /// While the internal object, `neovim_sys::vim::Object` is just fine to work with, this type
@@ -41,10 +44,26 @@ pub enum RustObject {
     /// `ObjectType::kObjectTypeDictionary`.
     ///
     Dictionary(Dictionary),
-    // LuaRef(LuaRef),
-    // Buffer,
-    // Window,
-    // Tabpage,
+
+    /// Represents a `neovim_sys::vim::Object` where its `object_type()` is
+    /// `ObjectType::kObjectTypeLuaRef`.
+    ///
+    LuaRef(LuaRef),
+
+    /// Represents a `neovim_sys::vim::Object` where its `object_type()` is
+    /// `ObjectType::kObjectTypeBuffer`.
+    ///
+    Buffer(Buffer),
+
+    /// Represents a `neovim_sys::vim::Object` where its `object_type()` is
+    /// `ObjectType::kObjectTypeWindow`.
+    ///
+    Window(Window),
+
+    /// Represents a `neovim_sys::vim::Object` where its `object_type()` is
+    /// `ObjectType::kObjectTypeTabpage`.
+    ///
+    Tabpage(Tabpage),
 }
 
 impl From<Object> for RustObject {
@@ -59,6 +78,10 @@ impl From<Object> for RustObject {
             ObjectType::kObjectTypeDictionary => {
                 Self::Dictionary(api_object.into_dictionary_unchecked())
             }
+            ObjectType::kObjectTypeLuaRef => Self::LuaRef(api_object.into_luaref_unchecked()),
+            ObjectType::kObjectTypeBuffer => Self::Buffer(api_object.into_buffer_unchecked()),
+            ObjectType::kObjectTypeWindow => Self::Window(api_object.into_window_unchecked()),
+            ObjectType::kObjectTypeTabpage => Self::Tabpage(api_object.into_tabpage_unchecked()),
         }
     }
 }
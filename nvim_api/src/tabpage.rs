@@ -0,0 +1,129 @@
+//!
+//! This module contains function wrappers for neovim functions defined in
+//! `neovim/src/nvim/api/tabpage.c`.
+//!
+use super::{Error, Integer, Tabpage, Window};
+use neovim_sys::api::{
+    self,
+    nvim::{LuaError, NvimString, Object},
+};
+
+/// Gets the windows in the tabpage.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_tabpage_list_wins(tabpage: Tabpage) -> Result<Vec<Window>, Error> {
+    let mut out_err = LuaError::default();
+
+    let windows = unsafe { api::tabpage::nvim_tabpage_list_wins(tabpage, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(windows.iter().map(Object::as_window_unchecked).collect())
+    }
+}
+
+/// Gets the current window in the tabpage.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_tabpage_get_win(tabpage: Tabpage) -> Result<Window, Error> {
+    let mut out_err = LuaError::default();
+
+    let window = unsafe { api::tabpage::nvim_tabpage_get_win(tabpage, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(window)
+    }
+}
+
+/// Gets the tabpage number; this is the same value as `tabpagenr()` and what's used by commands
+/// like `:tabnext`.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_tabpage_get_number(tabpage: Tabpage) -> Result<Integer, Error> {
+    let mut out_err = LuaError::default();
+
+    let number = unsafe { api::tabpage::nvim_tabpage_get_number(tabpage, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(number)
+    }
+}
+
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call.
+///
+pub fn nvim_tabpage_get_var(tabpage: Tabpage, name: &str) -> Result<Object, Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    let object = unsafe { api::tabpage::nvim_tabpage_get_var(tabpage, api_name, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(object)
+    }
+}
+
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call.
+///
+pub fn nvim_tabpage_set_var(tabpage: Tabpage, name: &str, value: Object) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    unsafe {
+        api::tabpage::nvim_tabpage_set_var(tabpage, api_name, value, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call (ex. the variable doesn't exist).
+///
+pub fn nvim_tabpage_del_var(tabpage: Tabpage, name: &str) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    unsafe {
+        api::tabpage::nvim_tabpage_del_var(tabpage, api_name, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if `tabpage` refers to a tabpage that still exists.
+///
+#[must_use]
+pub fn nvim_tabpage_is_valid(tabpage: Tabpage) -> bool {
+    unsafe { api::tabpage::nvim_tabpage_is_valid(tabpage) }
+}
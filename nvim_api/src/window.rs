@@ -0,0 +1,168 @@
+//!
+//! This module contains function wrappers for neovim functions defined in
+//! `neovim/src/nvim/api/window.c`.
+//!
+use super::{Buffer, Error, Integer, Window};
+use neovim_sys::api::{
+    self,
+    nvim::{Array, LuaError, NvimString, Object},
+};
+
+/// Gets the (1,0)-indexed cursor position in the window, as `(row, col)`.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+/// * If nvim returned something other than two `Integer`s.
+///
+pub fn nvim_win_get_cursor(window: Window) -> Result<(Integer, Integer), Error> {
+    let mut out_err = LuaError::default();
+
+    let pos = unsafe { api::window::nvim_win_get_cursor(window, &mut out_err) };
+
+    if out_err.is_err() {
+        return Err(Error::from(out_err));
+    }
+
+    match pos.as_slice() {
+        [row, col] => Ok((row.try_as_integer()?, col.try_as_integer()?)),
+        _ => Err(Error::Raw(format!(
+            "Expected cursor position of [row, col]; got {:?}",
+            pos
+        ))),
+    }
+}
+
+/// Sets the (1,0)-indexed cursor position in the window.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. the position is outside of the buffer).
+///
+pub fn nvim_win_set_cursor(window: Window, (row, col): (Integer, Integer)) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let pos = Array::new_from([Object::from(row), Object::from(col)]);
+
+    unsafe {
+        api::window::nvim_win_set_cursor(window, pos, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// Gets the current buffer in the window.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_win_get_buf(window: Window) -> Result<Buffer, Error> {
+    let mut out_err = LuaError::default();
+
+    let buffer = unsafe { api::window::nvim_win_get_buf(window, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(buffer)
+    }
+}
+
+/// Gets the window width, in columns.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_win_get_width(window: Window) -> Result<Integer, Error> {
+    let mut out_err = LuaError::default();
+
+    let width = unsafe { api::window::nvim_win_get_width(window, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(width)
+    }
+}
+
+/// Gets the window height, in rows.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call.
+///
+pub fn nvim_win_get_height(window: Window) -> Result<Integer, Error> {
+    let mut out_err = LuaError::default();
+
+    let height = unsafe { api::window::nvim_win_get_height(window, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(height)
+    }
+}
+
+/// Closes the window. With `force`, this is like `:close!`, allowing the window to be closed when
+/// its buffer has unsaved changes.
+///
+/// # Errors
+///
+/// * If nvim set an error on the call (ex. it's the last window).
+///
+pub fn nvim_win_close(window: Window, force: bool) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+
+    unsafe {
+        api::window::nvim_win_close(window, force, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
+
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call.
+///
+pub fn nvim_win_get_var(window: Window, name: &str) -> Result<Object, Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    let object = unsafe { api::window::nvim_win_get_var(window, api_name, &mut out_err) };
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(object)
+    }
+}
+
+/// # Errors
+///
+/// * If `name` can't be converted to a `NvimString`.
+/// * If nvim set an error on the call.
+///
+pub fn nvim_win_set_var(window: Window, name: &str, value: Object) -> Result<(), Error> {
+    let mut out_err = LuaError::default();
+    let api_name = NvimString::new(name)?;
+
+    unsafe {
+        api::window::nvim_win_set_var(window, api_name, value, &mut out_err);
+    }
+
+    if out_err.is_err() {
+        Err(Error::from(out_err))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+//!
+//! This module contains types and functions for abbreviations (see `:help abbreviations`).
+//!
+//! ```no_run
+//! use overkill_nvim::abbreviation::{AbbrevMode, Abbreviator};
+//!
+//! let abbreviator = Abbreviator::new(AbbrevMode::Insert);
+//! abbreviator.noreabbrev("teh", "the").unwrap();
+//! abbreviator.buffer().noreabbrev("adn", "and").unwrap();
+//!
+//! abbreviator.unabbreviate("teh").unwrap();
+//! ```
+//!
+use crate::buffer::Buffer;
+use nvim_api::{
+    keymap::{
+        clear_abbrevs, clear_buf_abbrevs, del_abbrev, del_buf_abbrev, set_abbrev, set_buf_abbrev,
+        set_buf_noreabbrev, set_noreabbrev, Error, SpecialArguments,
+    },
+    nvim,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// The modes that abbreviations can be defined for.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AbbrevMode {
+    /// Insert mode; "iabbrev".
+    ///
+    Insert,
+
+    /// Command-line mode; "cabbrev".
+    ///
+    CommandLine,
+
+    /// Insert and command-line modes; "abbreviate".
+    ///
+    #[default]
+    InsertAndCommandLine,
+}
+
+impl AbbrevMode {
+    /// The single char that represents the `AbbrevMode`.
+    ///
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Insert => "i",
+            Self::CommandLine => "c",
+            Self::InsertAndCommandLine => "!",
+        }
+    }
+
+    /// The command that lists the abbreviations for the mode.
+    ///
+    const fn list_command(self) -> &'static str {
+        match self {
+            Self::Insert => "iabbrev",
+            Self::CommandLine => "cabbrev",
+            Self::InsertAndCommandLine => "abbreviate",
+        }
+    }
+
+    const fn from_listing_char(c: char) -> Option<Self> {
+        match c {
+            'i' => Some(Self::Insert),
+            'c' => Some(Self::CommandLine),
+            '!' => Some(Self::InsertAndCommandLine),
+            _ => None,
+        }
+    }
+}
+
+/// An abbreviation, as listed by `:abbreviate` (which is the only way nvim provides for getting
+/// them).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abbreviation {
+    mode: AbbrevMode,
+    lhs: String,
+    rhs: String,
+    noremap: bool,
+    script: bool,
+    buffer: bool,
+}
+
+impl Abbreviation {
+    /// The `AbbrevMode` for this abbreviation.
+    ///
+    #[must_use]
+    pub const fn mode(&self) -> AbbrevMode {
+        self.mode
+    }
+
+    /// The left-hand side of the abbreviation.
+    ///
+    #[must_use]
+    pub const fn lhs(&self) -> &String {
+        &self.lhs
+    }
+
+    /// The right-hand side of the abbreviation.
+    ///
+    #[must_use]
+    pub const fn rhs(&self) -> &String {
+        &self.rhs
+    }
+
+    /// Was this abbreviation made with `noreabbrev`?
+    ///
+    #[must_use]
+    pub const fn noremap(&self) -> bool {
+        self.noremap
+    }
+
+    /// Is this a `<script>` abbreviation?
+    ///
+    #[must_use]
+    pub const fn script(&self) -> bool {
+        self.script
+    }
+
+    /// Is this a `<buffer>` abbreviation?
+    ///
+    #[must_use]
+    pub const fn buffer(&self) -> bool {
+        self.buffer
+    }
+
+    /// Parses a line of `:abbreviate` output. Lines look like `i  lhs         *@rhs`: the mode
+    /// takes 3 columns, the lhs is padded to at least 12 columns (with at least one space), then
+    /// there's a column for `*` (noremap) or `&` (script), one for `@` (buffer), and the rhs.
+    /// nvim pads by display cells, so a wide char in the lhs takes 2 columns.
+    ///
+    fn from_listing_line(line: &str) -> Option<Self> {
+        let chars: Vec<char> = line.chars().collect();
+        let mode = AbbrevMode::from_listing_char(*chars.first()?)?;
+
+        let lhs: String = chars
+            .get(3..)?
+            .iter()
+            .take_while(|c| !c.is_whitespace())
+            .collect();
+
+        if lhs.is_empty() {
+            return None;
+        }
+
+        let padding = 12_usize.saturating_sub(lhs.width()).max(1);
+        let flags_at = 3 + lhs.chars().count() + padding;
+        let remap_flag = *chars.get(flags_at)?;
+        let buffer_flag = *chars.get(flags_at + 1)?;
+
+        Some(Self {
+            mode,
+            lhs,
+            rhs: chars.get(flags_at + 2..)?.iter().collect(),
+            noremap: remap_flag == '*',
+            script: remap_flag == '&',
+            buffer: buffer_flag == '@',
+        })
+    }
+}
+
+/// This type provides the main API for getting and setting abbreviations.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Abbreviator {
+    mode: AbbrevMode,
+    arguments: SpecialArguments,
+}
+
+impl Abbreviator {
+    /// Basic constructor.
+    ///
+    #[must_use]
+    pub fn new(mode: AbbrevMode) -> Self {
+        Self {
+            mode,
+            arguments: SpecialArguments::default(),
+        }
+    }
+
+    /// Defines an abbreviation that expands `lhs` to `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the abbreviation (ex. `unique` is set and it already exists).
+    ///
+    pub fn abbreviate(&self, lhs: &str, rhs: &str) -> Result<(), Error> {
+        set_abbrev(self.mode.as_str(), lhs, rhs, Some(self.arguments))
+    }
+
+    /// Like `abbreviate()`, but `rhs` isn't remapped.
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the abbreviation (ex. `unique` is set and it already exists).
+    ///
+    pub fn noreabbrev(&self, lhs: &str, rhs: &str) -> Result<(), Error> {
+        set_noreabbrev(self.mode.as_str(), lhs, rhs, Some(self.arguments))
+    }
+
+    /// Like `abbreviate()`, but the abbreviation is local to `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the abbreviation.
+    ///
+    pub fn buf_abbreviate(&self, buffer: Buffer, lhs: &str, rhs: &str) -> Result<(), Error> {
+        set_buf_abbrev(
+            buffer.handle(),
+            self.mode.as_str(),
+            lhs,
+            rhs,
+            Some(self.arguments),
+        )
+    }
+
+    /// Like `noreabbrev()`, but the abbreviation is local to `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the abbreviation.
+    ///
+    pub fn buf_noreabbrev(&self, buffer: Buffer, lhs: &str, rhs: &str) -> Result<(), Error> {
+        set_buf_noreabbrev(
+            buffer.handle(),
+            self.mode.as_str(),
+            lhs,
+            rhs,
+            Some(self.arguments),
+        )
+    }
+
+    /// Deletes the abbreviation for `lhs`. If `buffer` is set, this deletes the current buffer's
+    /// abbreviation for `lhs`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::NoMatches` if there's no abbreviation for `lhs`.
+    /// * If `lhs` contains a nul byte.
+    ///
+    pub fn unabbreviate(&self, lhs: &str) -> Result<(), Error> {
+        del_abbrev(self.mode.as_str(), lhs, Some(self.arguments))
+    }
+
+    /// Deletes `buffer`'s abbreviation for `lhs`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::NoMatches` if `buffer` has no abbreviation for `lhs`.
+    /// * If `lhs` contains a nul byte, or `buffer` doesn't exist.
+    ///
+    pub fn buf_unabbreviate(&self, buffer: Buffer, lhs: &str) -> Result<(), Error> {
+        del_buf_abbrev(buffer.handle(), self.mode.as_str(), lhs)
+    }
+
+    /// Deletes all abbreviations for the `Abbreviator`'s `mode`, like `:abclear`. If `buffer` is
+    /// set, this deletes the current buffer's abbreviations instead.
+    ///
+    /// # Errors
+    ///
+    /// * If nvim fails to get the current buffer.
+    ///
+    pub fn clear(&self) -> Result<(), Error> {
+        if self.arguments.is_buffer() {
+            self.buf_clear(Buffer::current())
+        } else {
+            clear_abbrevs(self.mode.as_str())
+        }
+    }
+
+    /// Deletes all of `buffer`'s abbreviations for the `Abbreviator`'s `mode`.
+    ///
+    /// # Errors
+    ///
+    /// * If `buffer` doesn't exist.
+    ///
+    pub fn buf_clear(&self, buffer: Buffer) -> Result<(), Error> {
+        clear_buf_abbrevs(buffer.handle(), self.mode.as_str())
+    }
+
+    /// Gets the abbreviations that are defined for the `Abbreviator`'s `mode` (global ones and the
+    /// current buffer's). Note that, for example, an `AbbrevMode::InsertAndCommandLine`
+    /// abbreviation isn't listed for `AbbrevMode::Insert`, even though it applies in insert mode.
+    ///
+    /// # Errors
+    ///
+    /// * If nvim fails to list the abbreviations.
+    ///
+    pub fn list(&self) -> Result<Vec<Abbreviation>, Error> {
+        let output = nvim::nvim_exec(self.mode.list_command(), true)?.unwrap_or_default();
+
+        Ok(output
+            .lines()
+            .filter_map(Abbreviation::from_listing_line)
+            .filter(|abbreviation| abbreviation.mode == self.mode)
+            .collect())
+    }
+
+    def_bool_return_self_meth!(buffer);
+    def_bool_return_self_meth!(expr);
+    def_bool_return_self_meth!(script);
+    def_bool_return_self_meth!(silent);
+    def_bool_return_self_meth!(unique);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_listing_line() {
+        assert_eq!(
+            Abbreviation::from_listing_line("i  teh         *  the"),
+            Some(Abbreviation {
+                mode: AbbrevMode::Insert,
+                lhs: "teh".to_string(),
+                rhs: " the".to_string(),
+                noremap: true,
+                script: false,
+                buffer: false,
+            })
+        );
+
+        let abbreviation =
+            Abbreviation::from_listing_line("!  averylonglhs *@a very long rhs").unwrap();
+        assert_eq!(abbreviation.mode(), AbbrevMode::InsertAndCommandLine);
+        assert_eq!(abbreviation.lhs(), "averylonglhs");
+        assert_eq!(abbreviation.rhs(), "a very long rhs");
+        assert!(abbreviation.noremap());
+        assert!(abbreviation.buffer());
+
+        let abbreviation = Abbreviation::from_listing_line("c  wq            W").unwrap();
+        assert_eq!(abbreviation.mode(), AbbrevMode::CommandLine);
+        assert_eq!(abbreviation.rhs(), "W");
+        assert!(!abbreviation.noremap());
+        assert!(!abbreviation.buffer());
+
+        // Each of these chars takes 2 columns, so there are 8 spaces of padding, not 10.
+        let abbreviation = Abbreviation::from_listing_line("i  日本        * Japan").unwrap();
+        assert_eq!(abbreviation.lhs(), "日本");
+        assert_eq!(abbreviation.rhs(), "Japan");
+        assert!(abbreviation.noremap());
+
+        assert_eq!(Abbreviation::from_listing_line(""), None);
+        assert_eq!(
+            Abbreviation::from_listing_line("No abbreviation found"),
+            None
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//!
+//! This module contains traits for converting Rust values into the `Object`s and `Array`s that
+//! get passed as arguments to Vimscript (and Lua) functions.
+//!
+//! ```
+//! use overkill_nvim::args::IntoArgs;
+//!
+//! let args = ("%:p", 1, true).into_args().unwrap();
+//! assert_eq!(args.len(), 3);
+//! ```
+//!
+use nvim_api::{Array, Boolean, Dictionary, Error, Float, Integer, NvimString, Object};
+
+/// For types that can be converted to an `Object`. Unlike `Into<Object>`, this is implemented for
+/// strings, which can fail to convert if they contain a nul byte.
+///
+pub trait IntoObject {
+    /// Converts `self` to an `Object`.
+    ///
+    /// # Errors
+    ///
+    /// * If `self` (or something in it) is a string that contains a nul byte.
+    ///
+    fn into_object(self) -> Result<Object, Error>;
+}
+
+macro_rules! impl_into_object_from {
+    ($($t:ty),+) => {
+        $(
+            impl IntoObject for $t {
+                fn into_object(self) -> Result<Object, Error> {
+                    Ok(Object::from(self))
+                }
+            }
+        )+
+    };
+}
+
+impl_into_object_from!(
+    Object, Boolean, Integer, i8, u8, i16, u16, i32, u32, Float, NvimString, Array, Dictionary
+);
+
+impl IntoObject for &str {
+    fn into_object(self) -> Result<Object, Error> {
+        Ok(Object::from(NvimString::new(self)?))
+    }
+}
+
+impl IntoObject for String {
+    fn into_object(self) -> Result<Object, Error> {
+        Ok(Object::from(NvimString::new(self)?))
+    }
+}
+
+impl IntoObject for &String {
+    fn into_object(self) -> Result<Object, Error> {
+        self.as_str().into_object()
+    }
+}
+
+impl<T: IntoObject> IntoObject for Vec<T> {
+    fn into_object(self) -> Result<Object, Error> {
+        self.into_args().map(Object::from)
+    }
+}
+
+/// For types that can be converted to an `Array` of function arguments: tuples (up to 8 items)
+/// of `IntoObject`s, `Vec`s of `IntoObject`s, and `Array`s.
+///
+pub trait IntoArgs {
+    /// Converts `self` to an `Array` of arguments.
+    ///
+    /// # Errors
+    ///
+    /// * If any of the arguments is a string that contains a nul byte.
+    ///
+    fn into_args(self) -> Result<Array, Error>;
+}
+
+impl IntoArgs for () {
+    fn into_args(self) -> Result<Array, Error> {
+        Ok(Array::new())
+    }
+}
+
+impl IntoArgs for Array {
+    fn into_args(self) -> Result<Array, Error> {
+        Ok(self)
+    }
+}
+
+impl<T: IntoObject> IntoArgs for Vec<T> {
+    fn into_args(self) -> Result<Array, Error> {
+        self.into_iter()
+            .map(IntoObject::into_object)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Array::new_from)
+    }
+}
+
+macro_rules! impl_into_args_for_tuple {
+    ($($t:ident),+) => {
+        impl<$($t: IntoObject),+> IntoArgs for ($($t,)+) {
+            #[allow(non_snake_case)]
+            fn into_args(self) -> Result<Array, Error> {
+                let ($($t,)+) = self;
+
+                Ok(Array::new_from([$($t.into_object()?),+]))
+            }
+        }
+    };
+}
+
+impl_into_args_for_tuple!(A);
+impl_into_args_for_tuple!(A, B);
+impl_into_args_for_tuple!(A, B, C);
+impl_into_args_for_tuple!(A, B, C, D);
+impl_into_args_for_tuple!(A, B, C, D, E);
+impl_into_args_for_tuple!(A, B, C, D, E, F);
+impl_into_args_for_tuple!(A, B, C, D, E, F, G);
+impl_into_args_for_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_args() {
+        assert!(().into_args().unwrap().is_empty());
+
+        let args = ("one", 2, 3.0, false).into_args().unwrap();
+        assert_eq!(
+            args,
+            Array::new_from([
+                Object::from(NvimString::new_unchecked("one")),
+                Object::from(2),
+                Object::from(3.0),
+                Object::from(false),
+            ])
+        );
+
+        let args = (vec!["a", "b"],).into_args().unwrap();
+        assert_eq!(
+            args,
+            Array::new_from([Object::from(Array::new_from([
+                Object::from(NvimString::new_unchecked("a")),
+                Object::from(NvimString::new_unchecked("b")),
+            ]))])
+        );
+
+        assert_eq!(vec![1, 2].into_args().unwrap().len(), 2);
+        assert!(("o\0ne", 2).into_args().is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//!
+//! This module contains the `Buffer` type, for working with neovim buffers.
+//!
+use nvim_api::{buffer, nvim, Error, Integer};
+use std::ops::RangeBounds;
+
+/// Wraps nvim's buffer handle (the buffer number) and provides methods for working with that
+/// buffer.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Buffer(nvim_api::Buffer);
+
+impl Buffer {
+    /// nvim treats buffer `0` as "the current buffer" in all API calls; this is that buffer.
+    ///
+    pub const CURRENT: Self = Self(nvim_api::Buffer::CURRENT);
+
+    /// Gets the current buffer. Unlike `Buffer::CURRENT`, this resolves the actual buffer number.
+    ///
+    #[must_use]
+    pub fn current() -> Self {
+        Self(nvim::nvim_get_current_buf())
+    }
+
+    /// Creates a new, empty, unnamed buffer. A `scratch` buffer is a "throwaway" buffer (see
+    /// `:h scratch-buffer`).
+    ///
+    /// # Errors
+    ///
+    /// * If nvim fails to create the buffer.
+    ///
+    pub fn create(listed: bool, scratch: bool) -> Result<Self, Error> {
+        nvim::nvim_create_buf(listed, scratch).map(Self)
+    }
+
+    /// Gets all buffers, including unlisted (unloaded/deleted) buffers.
+    ///
+    #[must_use]
+    pub fn list() -> Vec<Self> {
+        nvim::nvim_list_bufs().into_iter().map(Self).collect()
+    }
+
+    /// The buffer number.
+    ///
+    #[must_use]
+    pub const fn handle(self) -> nvim_api::Buffer {
+        self.0
+    }
+
+    /// Deletes the buffer.
+    ///
+    /// # Errors
+    ///
+    /// * If nvim fails to delete the buffer (ex. the buffer has unsaved changes and
+    ///   `DeleteOptions::force()` wasn't set).
+    ///
+    pub fn delete(self, options: DeleteOptions) -> Result<(), Error> {
+        buffer::nvim_buf_delete(self.0, options.force, options.unload)
+    }
+
+    /// The full file name of the buffer; this is empty for unnamed buffers.
+    ///
+    /// # Errors
+    ///
+    /// * If the buffer is invalid.
+    ///
+    pub fn name(self) -> Result<String, Error> {
+        let name = buffer::nvim_buf_get_name(self.0)?;
+
+        // For unnamed buffers, nvim hands back a string with no data at all (not even a nul byte).
+        if name.as_ptr().is_null() {
+            Ok(String::new())
+        } else {
+            Ok(String::from(name))
+        }
+    }
+
+    /// Sets the full file name of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// * If the buffer is invalid.
+    /// * If another buffer already has that name.
+    ///
+    pub fn set_name(self, name: &str) -> Result<(), Error> {
+        buffer::nvim_buf_set_name(self.0, name)
+    }
+
+    /// Is the buffer valid and loaded?
+    ///
+    #[must_use]
+    pub fn is_loaded(self) -> bool {
+        buffer::nvim_buf_is_loaded(self.0)
+    }
+
+    /// Is the buffer valid? Note that a valid buffer may still be unloaded.
+    ///
+    #[must_use]
+    pub fn is_valid(self) -> bool {
+        buffer::nvim_buf_is_valid(self.0)
+    }
+
+    /// The buffer's `b:changedtick`, which is incremented for every change to the buffer. Compare
+    /// values of this to find out if the buffer has been modified since the last check.
+    ///
+    /// # Errors
+    ///
+    /// * If the buffer is invalid.
+    ///
+    pub fn changedtick(self) -> Result<Integer, Error> {
+        buffer::nvim_buf_get_changedtick(self.0)
+    }
+
+    /// The number of lines in the buffer.
+    ///
+    /// # Errors
+    ///
+    /// * If the buffer is invalid.
+    ///
+    pub fn line_count(self) -> Result<Integer, Error> {
+        buffer::nvim_buf_line_count(self.0)
+    }
+
+    /// Gets the lines in `range`; see `nvim_api::buffer::nvim_buf_get_lines()` for how ranges
+    /// work.
+    ///
+    /// # Errors
+    ///
+    /// * If the buffer is invalid.
+    /// * If `range` is out of bounds.
+    ///
+    pub fn lines<R>(self, range: R) -> Result<Vec<String>, Error>
+    where
+        R: RangeBounds<Integer>,
+    {
+        buffer::nvim_buf_get_lines(self.0, range, true)
+    }
+
+    /// Replaces the lines in `range` with `replacement`; see
+    /// `nvim_api::buffer::nvim_buf_set_lines()` for how ranges work.
+    ///
+    /// # Errors
+    ///
+    /// * If the buffer is invalid.
+    /// * If `range` is out of bounds.
+    /// * If any of the lines contain a newline or nul byte.
+    ///
+    pub fn set_lines<R, S>(self, range: R, replacement: &[S]) -> Result<(), Error>
+    where
+        R: RangeBounds<Integer>,
+        S: AsRef<str>,
+    {
+        buffer::nvim_buf_set_lines(self.0, range, true, replacement)
+    }
+}
+
+impl From<nvim_api::Buffer> for Buffer {
+    fn from(handle: nvim_api::Buffer) -> Self {
+        Self(handle)
+    }
+}
+
+impl From<Buffer> for nvim_api::Buffer {
+    fn from(buffer: Buffer) -> Self {
+        buffer.0
+    }
+}
+
+/// Options for `Buffer::delete()`.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteOptions {
+    force: bool,
+    unload: bool,
+}
+
+impl DeleteOptions {
+    /// Delete the buffer even if it has unsaved changes.
+    ///
+    #[must_use]
+    pub const fn force(self) -> Self {
+        Self {
+            force: true,
+            ..self
+        }
+    }
+
+    /// Only unload the buffer (see `:bunload`) instead of wiping it out (see `:bwipeout`).
+    ///
+    #[must_use]
+    pub const fn unload(self) -> Self {
+        Self {
+            unload: true,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,466 @@
+//!
+//! This module contains the `Color` type, for working with colors in highlight groups and
+//! colorschemes.
+//!
+//! A `Color` is always RGB; when nvim needs a cterm color (i.e. `'notermguicolors'`), the nearest
+//! color in the xterm 256-color palette is used instead.
+//!
+mod names;
+
+use self::names::NAMED_COLORS;
+use crate::option::{BooleanOption, NvimOptionError, TermGuiColors};
+use nvim_api::{Dictionary, Integer, NvimString};
+use std::{fmt, str::FromStr};
+
+/// The 16 "system" colors of the xterm palette. Terminals commonly change these, so
+/// `Color::to_cterm()` never picks them.
+///
+const XTERM_SYSTEM_COLORS: [u32; 16] = [
+    0x00_0000, 0x80_0000, 0x00_8000, 0x80_8000, 0x00_0080, 0x80_0080, 0x00_8080, 0xc0_c0c0,
+    0x80_8080, 0xff_0000, 0x00_ff00, 0xff_ff00, 0x00_00ff, 0xff_00ff, 0x00_ffff, 0xff_ffff,
+];
+
+/// The value of each step of the 6x6x6 color cube (indices 16-231) of the xterm palette.
+///
+const XTERM_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Error for when a string can't be parsed as a `Color`.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Invalid color: '{}'", _0)]
+pub struct InvalidColor(String);
+
+/// An RGB color.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+impl Color {
+    /// Basic constructor.
+    ///
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Instantiates from a `0xRRGGBB` integer; anything above `0xFFFFFF` is ignored.
+    ///
+    #[must_use]
+    pub const fn from_rgb(rgb: u32) -> Self {
+        let [_, r, g, b] = rgb.to_be_bytes();
+
+        Self { r, g, b }
+    }
+
+    /// Instantiates from an index into the xterm 256-color palette.
+    ///
+    #[must_use]
+    pub const fn from_cterm(index: u8) -> Self {
+        match index {
+            0..=15 => Self::from_rgb(XTERM_SYSTEM_COLORS[index as usize]),
+            16..=231 => {
+                let i = index - 16;
+
+                Self::new(
+                    XTERM_CUBE_LEVELS[(i / 36) as usize],
+                    XTERM_CUBE_LEVELS[((i / 6) % 6) as usize],
+                    XTERM_CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            232..=255 => {
+                let level = 8 + (index - 232) * 10;
+
+                Self::new(level, level, level)
+            }
+        }
+    }
+
+    /// The red component.
+    ///
+    #[must_use]
+    pub const fn r(self) -> u8 {
+        self.r
+    }
+
+    /// The green component.
+    ///
+    #[must_use]
+    pub const fn g(self) -> u8 {
+        self.g
+    }
+
+    /// The blue component.
+    ///
+    #[must_use]
+    pub const fn b(self) -> u8 {
+        self.b
+    }
+
+    /// The color as a `0xRRGGBB` integer; this is what `nvim_set_hl()` expects for GUI colors.
+    ///
+    #[must_use]
+    pub const fn rgb(self) -> u32 {
+        u32::from_be_bytes([0, self.r, self.g, self.b])
+    }
+
+    /// The index of the nearest color in the xterm 256-color palette. Only the color cube
+    /// (16-231) and grayscale ramp (232-255) are considered, since the first 16 colors vary by
+    /// terminal.
+    ///
+    #[must_use]
+    pub fn to_cterm(self) -> u8 {
+        let nearest_level_index = |component: u8| -> u8 {
+            match component {
+                0..=47 => 0,
+                48..=114 => 1,
+                _ => (component - 35) / 40,
+            }
+        };
+
+        let (ri, gi, bi) = (
+            nearest_level_index(self.r),
+            nearest_level_index(self.g),
+            nearest_level_index(self.b),
+        );
+        let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+        let average = (u16::from(self.r) + u16::from(self.g) + u16::from(self.b)) / 3;
+        let gray_index = match average {
+            0..=7 => 232,
+            238..=u16::MAX => 255,
+            // This is at most 23, so it always fits in a u8.
+            #[allow(clippy::cast_possible_truncation)]
+            a => 232 + ((a - 3) / 10) as u8,
+        };
+
+        if self.distance_squared(Self::from_cterm(gray_index))
+            < self.distance_squared(Self::from_cterm(cube_index))
+        {
+            gray_index
+        } else {
+            cube_index
+        }
+    }
+
+    /// Mixes `self` with `other`. `ratio` is how much of `other` to use, from `0.0` (all `self`)
+    /// to `1.0` (all `other`); it gets clamped to that range.
+    ///
+    #[must_use]
+    pub fn blend(self, other: Self, ratio: f64) -> Self {
+        let ratio = ratio.clamp(0.0, 1.0);
+
+        // Since `ratio` is clamped, the result is always between the two components, which are
+        // both u8s.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mix = |a: u8, b: u8| -> u8 {
+            f64::from(b)
+                .mul_add(ratio, f64::from(a) * (1.0 - ratio))
+                .round() as u8
+        };
+
+        Self::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+
+    /// Mixes `self` with white; `amount` is from `0.0` (no change) to `1.0` (white).
+    ///
+    #[must_use]
+    pub fn lighten(self, amount: f64) -> Self {
+        self.blend(Self::new(255, 255, 255), amount)
+    }
+
+    /// Mixes `self` with black; `amount` is from `0.0` (no change) to `1.0` (black).
+    ///
+    #[must_use]
+    pub fn darken(self, amount: f64) -> Self {
+        self.blend(Self::new(0, 0, 0), amount)
+    }
+
+    /// Sets the GUI and cterm entries for `key` (ex. `fg` and `ctermfg`) in `dict`, which is
+    /// meant for passing to `nvim_set_hl()`. Setting both means the highlight group works
+    /// regardless of `'termguicolors'`.
+    ///
+    pub fn write_to(self, dict: &mut Dictionary, key: ColorKey) {
+        dict.set(
+            NvimString::new_unchecked(key.gui()),
+            Integer::from(self.rgb()),
+        );
+
+        if let Some(cterm) = key.cterm() {
+            dict.set(NvimString::new_unchecked(cterm), self.to_cterm());
+        }
+    }
+
+    /// The color as a `String` for `mode`: `#rrggbb` for `ColorMode::Gui`, or the xterm palette
+    /// index for `ColorMode::Cterm`. This is the format that `:highlight` uses.
+    ///
+    #[must_use]
+    pub fn to_string_for(self, mode: ColorMode) -> String {
+        match mode {
+            ColorMode::Gui => self.to_string(),
+            ColorMode::Cterm => self.to_cterm().to_string(),
+        }
+    }
+
+    fn distance_squared(self, other: Self) -> u32 {
+        let d = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+
+        d(self.r, other.r) + d(self.g, other.g) + d(self.b, other.b)
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    /// Parses `#rrggbb`, names of colors from `:h gui-colors` (case-insensitive), and cterm
+    /// palette indices (`0`-`255`).
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = s.strip_prefix('#') {
+            if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Ok(Self::from_rgb(u32::from_str_radix(hex, 16).unwrap()));
+            }
+
+            return Err(InvalidColor(s.to_string()));
+        }
+
+        if let Ok(index) = s.parse::<u8>() {
+            return Ok(Self::from_cterm(index));
+        }
+
+        let name = s.to_ascii_lowercase();
+
+        NAMED_COLORS
+            .binary_search_by_key(&name.as_str(), |(n, _)| n)
+            .map(|i| Self::from_rgb(NAMED_COLORS[i].1))
+            .map_err(|_| InvalidColor(s.to_string()))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:06x}", self.rgb())
+    }
+}
+
+impl From<Color> for NvimString {
+    fn from(value: Color) -> Self {
+        Self::new_unchecked(value.to_string())
+    }
+}
+
+/// Which color of a highlight group a `Color` is for.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorKey {
+    /// Foreground: `fg`/`ctermfg`.
+    ///
+    Fg,
+
+    /// Background: `bg`/`ctermbg`.
+    ///
+    Bg,
+
+    /// Special (undercurl/underline): `sp`. There's no cterm equivalent.
+    ///
+    Sp,
+}
+
+impl ColorKey {
+    const fn gui(self) -> &'static str {
+        match self {
+            Self::Fg => "fg",
+            Self::Bg => "bg",
+            Self::Sp => "sp",
+        }
+    }
+
+    const fn cterm(self) -> Option<&'static str> {
+        match self {
+            Self::Fg => Some("ctermfg"),
+            Self::Bg => Some("ctermbg"),
+            Self::Sp => None,
+        }
+    }
+}
+
+/// Which colors nvim is currently using to draw: GUI (RGB) colors or cterm (palette) colors.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// RGB colors; this is the case in GUIs and with `'termguicolors'`.
+    ///
+    Gui,
+
+    /// Palette colors; this is the case in terminals with `'notermguicolors'`.
+    ///
+    Cterm,
+}
+
+impl ColorMode {
+    /// Gets the current mode, based on `'termguicolors'`.
+    ///
+    /// # Errors
+    ///
+    /// * If getting `'termguicolors'` fails.
+    ///
+    pub fn current() -> Result<Self, NvimOptionError> {
+        if TermGuiColors::get()? {
+            Ok(Self::Gui)
+        } else {
+            Ok(Self::Cterm)
+        }
+    }
+}
+
+impl From<bool> for ColorMode {
+    /// Converts a `'termguicolors'` value.
+    ///
+    fn from(termguicolors: bool) -> Self {
+        if termguicolors {
+            Self::Gui
+        } else {
+            Self::Cterm
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "#c0ffee".parse::<Color>().unwrap(),
+            Color::new(0xc0, 0xff, 0xee)
+        );
+        assert_eq!(
+            "#C0FFEE".parse::<Color>().unwrap(),
+            Color::new(0xc0, 0xff, 0xee)
+        );
+        assert_eq!("Red".parse::<Color>().unwrap(), Color::new(255, 0, 0));
+        assert_eq!(
+            "DarkYellow".parse::<Color>().unwrap(),
+            Color::from_rgb(0xbb_bb00)
+        );
+        assert_eq!(
+            "lightgrey".parse::<Color>().unwrap(),
+            Color::from_rgb(0xd3_d3d3)
+        );
+        assert_eq!("196".parse::<Color>().unwrap(), Color::new(255, 0, 0));
+        assert_eq!("0".parse::<Color>().unwrap(), Color::new(0, 0, 0));
+
+        assert!("c0ffee".parse::<Color>().is_err());
+        assert!("#c0ffe".parse::<Color>().is_err());
+        assert!("#c0ffeg".parse::<Color>().is_err());
+        assert!("#+0ffee".parse::<Color>().is_err());
+        assert!("256".parse::<Color>().is_err());
+        assert!("Redd".parse::<Color>().is_err());
+        assert!("Slate Gray".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_named() {
+        assert_eq!("Gold".parse::<Color>().unwrap(), Color::new(255, 215, 0));
+        assert_eq!(
+            "SlateGray".parse::<Color>().unwrap(),
+            Color::new(112, 128, 144)
+        );
+        assert_eq!(
+            "grey50".parse::<Color>().unwrap(),
+            Color::new(127, 127, 127)
+        );
+        assert_eq!(
+            "RebeccaPurple".parse::<Color>().unwrap(),
+            Color::new(0x66, 0x33, 0x99)
+        );
+        assert_eq!(
+            "NvimDarkBlue".parse::<Color>().unwrap(),
+            Color::new(0x00, 0x4c, 0x73)
+        );
+        assert_eq!(
+            "LightRed".parse::<Color>().unwrap(),
+            Color::new(0xff, 0xbb, 0xbb)
+        );
+
+        // The lookup is a binary search.
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Color::new(0xc0, 0xff, 0xee).to_string(), "#c0ffee");
+        assert_eq!(Color::new(0, 0, 1).to_string(), "#000001");
+    }
+
+    #[test]
+    fn test_from_cterm() {
+        assert_eq!(Color::from_cterm(1), Color::from_rgb(0x80_0000));
+        assert_eq!(Color::from_cterm(16), Color::new(0, 0, 0));
+        assert_eq!(Color::from_cterm(21), Color::new(0, 0, 255));
+        assert_eq!(Color::from_cterm(67), Color::new(95, 135, 175));
+        assert_eq!(Color::from_cterm(231), Color::new(255, 255, 255));
+        assert_eq!(Color::from_cterm(232), Color::new(8, 8, 8));
+        assert_eq!(Color::from_cterm(255), Color::new(238, 238, 238));
+    }
+
+    #[test]
+    fn test_to_cterm() {
+        // Every color in the cube and grayscale ramp should map back to itself.
+        for index in 16..=255 {
+            assert_eq!(Color::from_cterm(index).to_cterm(), index);
+        }
+
+        assert_eq!(Color::new(255, 0, 0).to_cterm(), 196);
+        assert_eq!(Color::new(0, 0, 0).to_cterm(), 16);
+        assert_eq!(Color::new(250, 250, 250).to_cterm(), 231);
+        assert_eq!(Color::new(0x30, 0x30, 0x30).to_cterm(), 236);
+        assert_eq!(Color::new(0x5f, 0x87, 0xaa).to_cterm(), 67);
+    }
+
+    #[test]
+    fn test_blend() {
+        let black = Color::new(0, 0, 0);
+        let white = Color::new(255, 255, 255);
+
+        assert_eq!(black.blend(white, 0.0), black);
+        assert_eq!(black.blend(white, 1.0), white);
+        assert_eq!(black.blend(white, 0.5), Color::new(128, 128, 128));
+        assert_eq!(black.blend(white, 42.0), white);
+
+        assert_eq!(
+            Color::new(100, 0, 200).lighten(0.5),
+            Color::new(178, 128, 228)
+        );
+        assert_eq!(Color::new(100, 0, 200).darken(0.5), Color::new(50, 0, 100));
+    }
+
+    #[test]
+    fn test_write_to() {
+        let mut dict = Dictionary::new();
+        Color::new(255, 0, 0).write_to(&mut dict, ColorKey::Fg);
+        Color::new(0, 0, 255).write_to(&mut dict, ColorKey::Sp);
+
+        assert_eq!(dict.get_as_integer("fg"), Some(0xff_0000));
+        assert_eq!(dict.get_as_integer("ctermfg"), Some(196));
+        assert_eq!(dict.get_as_integer("sp"), Some(0x00_00ff));
+        assert!(dict.get("ctermsp").is_none());
+    }
+
+    #[test]
+    fn test_to_string_for() {
+        let color = Color::new(255, 0, 0);
+
+        assert_eq!(color.to_string_for(ColorMode::Gui), "#ff0000");
+        assert_eq!(color.to_string_for(ColorMode::Cterm), "196");
+        assert_eq!(ColorMode::from(true), ColorMode::Gui);
+    }
+}
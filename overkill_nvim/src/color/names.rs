@@ -0,0 +1,712 @@
+//! The named colors that nvim understands (see `:h gui-colors`), from nvim's `color_name_table`:
+//! the X11 colors (`rgb.txt`), a few that Vim adds (ex. `LightRed`), and nvim's own (ex.
+//! `NvimDarkBlue`).
+//!
+
+/// Lowercased names and their values, sorted by name (so they can be binary-searched).
+///
+pub(super) static NAMED_COLORS: [(&str, u32); 703] = [
+    ("aliceblue", 0xf0_f8ff),
+    ("antiquewhite", 0xfa_ebd7),
+    ("antiquewhite1", 0xff_efdb),
+    ("antiquewhite2", 0xee_dfcc),
+    ("antiquewhite3", 0xcd_c0b0),
+    ("antiquewhite4", 0x8b_8378),
+    ("aqua", 0x00_ffff),
+    ("aquamarine", 0x7f_ffd4),
+    ("aquamarine1", 0x7f_ffd4),
+    ("aquamarine2", 0x76_eec6),
+    ("aquamarine3", 0x66_cdaa),
+    ("aquamarine4", 0x45_8b74),
+    ("azure", 0xf0_ffff),
+    ("azure1", 0xf0_ffff),
+    ("azure2", 0xe0_eeee),
+    ("azure3", 0xc1_cdcd),
+    ("azure4", 0x83_8b8b),
+    ("beige", 0xf5_f5dc),
+    ("bisque", 0xff_e4c4),
+    ("bisque1", 0xff_e4c4),
+    ("bisque2", 0xee_d5b7),
+    ("bisque3", 0xcd_b79e),
+    ("bisque4", 0x8b_7d6b),
+    ("black", 0x00_0000),
+    ("blanchedalmond", 0xff_ebcd),
+    ("blue", 0x00_00ff),
+    ("blue1", 0x00_00ff),
+    ("blue2", 0x00_00ee),
+    ("blue3", 0x00_00cd),
+    ("blue4", 0x00_008b),
+    ("blueviolet", 0x8a_2be2),
+    ("brown", 0xa5_2a2a),
+    ("brown1", 0xff_4040),
+    ("brown2", 0xee_3b3b),
+    ("brown3", 0xcd_3333),
+    ("brown4", 0x8b_2323),
+    ("burlywood", 0xde_b887),
+    ("burlywood1", 0xff_d39b),
+    ("burlywood2", 0xee_c591),
+    ("burlywood3", 0xcd_aa7d),
+    ("burlywood4", 0x8b_7355),
+    ("cadetblue", 0x5f_9ea0),
+    ("cadetblue1", 0x98_f5ff),
+    ("cadetblue2", 0x8e_e5ee),
+    ("cadetblue3", 0x7a_c5cd),
+    ("cadetblue4", 0x53_868b),
+    ("chartreuse", 0x7f_ff00),
+    ("chartreuse1", 0x7f_ff00),
+    ("chartreuse2", 0x76_ee00),
+    ("chartreuse3", 0x66_cd00),
+    ("chartreuse4", 0x45_8b00),
+    ("chocolate", 0xd2_691e),
+    ("chocolate1", 0xff_7f24),
+    ("chocolate2", 0xee_7621),
+    ("chocolate3", 0xcd_661d),
+    ("chocolate4", 0x8b_4513),
+    ("coral", 0xff_7f50),
+    ("coral1", 0xff_7256),
+    ("coral2", 0xee_6a50),
+    ("coral3", 0xcd_5b45),
+    ("coral4", 0x8b_3e2f),
+    ("cornflowerblue", 0x64_95ed),
+    ("cornsilk", 0xff_f8dc),
+    ("cornsilk1", 0xff_f8dc),
+    ("cornsilk2", 0xee_e8cd),
+    ("cornsilk3", 0xcd_c8b1),
+    ("cornsilk4", 0x8b_8878),
+    ("crimson", 0xdc_143c),
+    ("cyan", 0x00_ffff),
+    ("cyan1", 0x00_ffff),
+    ("cyan2", 0x00_eeee),
+    ("cyan3", 0x00_cdcd),
+    ("cyan4", 0x00_8b8b),
+    ("darkblue", 0x00_008b),
+    ("darkcyan", 0x00_8b8b),
+    ("darkgoldenrod", 0xb8_860b),
+    ("darkgoldenrod1", 0xff_b90f),
+    ("darkgoldenrod2", 0xee_ad0e),
+    ("darkgoldenrod3", 0xcd_950c),
+    ("darkgoldenrod4", 0x8b_6508),
+    ("darkgray", 0xa9_a9a9),
+    ("darkgreen", 0x00_6400),
+    ("darkgrey", 0xa9_a9a9),
+    ("darkkhaki", 0xbd_b76b),
+    ("darkmagenta", 0x8b_008b),
+    ("darkolivegreen", 0x55_6b2f),
+    ("darkolivegreen1", 0xca_ff70),
+    ("darkolivegreen2", 0xbc_ee68),
+    ("darkolivegreen3", 0xa2_cd5a),
+    ("darkolivegreen4", 0x6e_8b3d),
+    ("darkorange", 0xff_8c00),
+    ("darkorange1", 0xff_7f00),
+    ("darkorange2", 0xee_7600),
+    ("darkorange3", 0xcd_6600),
+    ("darkorange4", 0x8b_4500),
+    ("darkorchid", 0x99_32cc),
+    ("darkorchid1", 0xbf_3eff),
+    ("darkorchid2", 0xb2_3aee),
+    ("darkorchid3", 0x9a_32cd),
+    ("darkorchid4", 0x68_228b),
+    ("darkred", 0x8b_0000),
+    ("darksalmon", 0xe9_967a),
+    ("darkseagreen", 0x8f_bc8f),
+    ("darkseagreen1", 0xc1_ffc1),
+    ("darkseagreen2", 0xb4_eeb4),
+    ("darkseagreen3", 0x9b_cd9b),
+    ("darkseagreen4", 0x69_8b69),
+    ("darkslateblue", 0x48_3d8b),
+    ("darkslategray", 0x2f_4f4f),
+    ("darkslategray1", 0x97_ffff),
+    ("darkslategray2", 0x8d_eeee),
+    ("darkslategray3", 0x79_cdcd),
+    ("darkslategray4", 0x52_8b8b),
+    ("darkslategrey", 0x2f_4f4f),
+    ("darkturquoise", 0x00_ced1),
+    ("darkviolet", 0x94_00d3),
+    ("darkyellow", 0xbb_bb00),
+    ("debianred", 0xd7_0751),
+    ("deeppink", 0xff_1493),
+    ("deeppink1", 0xff_1493),
+    ("deeppink2", 0xee_1289),
+    ("deeppink3", 0xcd_1076),
+    ("deeppink4", 0x8b_0a50),
+    ("deepskyblue", 0x00_bfff),
+    ("deepskyblue1", 0x00_bfff),
+    ("deepskyblue2", 0x00_b2ee),
+    ("deepskyblue3", 0x00_9acd),
+    ("deepskyblue4", 0x00_688b),
+    ("dimgray", 0x69_6969),
+    ("dimgrey", 0x69_6969),
+    ("dodgerblue", 0x1e_90ff),
+    ("dodgerblue1", 0x1e_90ff),
+    ("dodgerblue2", 0x1c_86ee),
+    ("dodgerblue3", 0x18_74cd),
+    ("dodgerblue4", 0x10_4e8b),
+    ("firebrick", 0xb2_2222),
+    ("firebrick1", 0xff_3030),
+    ("firebrick2", 0xee_2c2c),
+    ("firebrick3", 0xcd_2626),
+    ("firebrick4", 0x8b_1a1a),
+    ("floralwhite", 0xff_faf0),
+    ("forestgreen", 0x22_8b22),
+    ("fuchsia", 0xff_00ff),
+    ("gainsboro", 0xdc_dcdc),
+    ("ghostwhite", 0xf8_f8ff),
+    ("gold", 0xff_d700),
+    ("gold1", 0xff_d700),
+    ("gold2", 0xee_c900),
+    ("gold3", 0xcd_ad00),
+    ("gold4", 0x8b_7500),
+    ("goldenrod", 0xda_a520),
+    ("goldenrod1", 0xff_c125),
+    ("goldenrod2", 0xee_b422),
+    ("goldenrod3", 0xcd_9b1d),
+    ("goldenrod4", 0x8b_6914),
+    ("gray", 0xbe_bebe),
+    ("gray0", 0x00_0000),
+    ("gray1", 0x03_0303),
+    ("gray10", 0x1a_1a1a),
+    ("gray100", 0xff_ffff),
+    ("gray11", 0x1c_1c1c),
+    ("gray12", 0x1f_1f1f),
+    ("gray13", 0x21_2121),
+    ("gray14", 0x24_2424),
+    ("gray15", 0x26_2626),
+    ("gray16", 0x29_2929),
+    ("gray17", 0x2b_2b2b),
+    ("gray18", 0x2e_2e2e),
+    ("gray19", 0x30_3030),
+    ("gray2", 0x05_0505),
+    ("gray20", 0x33_3333),
+    ("gray21", 0x36_3636),
+    ("gray22", 0x38_3838),
+    ("gray23", 0x3b_3b3b),
+    ("gray24", 0x3d_3d3d),
+    ("gray25", 0x40_4040),
+    ("gray26", 0x42_4242),
+    ("gray27", 0x45_4545),
+    ("gray28", 0x47_4747),
+    ("gray29", 0x4a_4a4a),
+    ("gray3", 0x08_0808),
+    ("gray30", 0x4d_4d4d),
+    ("gray31", 0x4f_4f4f),
+    ("gray32", 0x52_5252),
+    ("gray33", 0x54_5454),
+    ("gray34", 0x57_5757),
+    ("gray35", 0x59_5959),
+    ("gray36", 0x5c_5c5c),
+    ("gray37", 0x5e_5e5e),
+    ("gray38", 0x61_6161),
+    ("gray39", 0x63_6363),
+    ("gray4", 0x0a_0a0a),
+    ("gray40", 0x66_6666),
+    ("gray41", 0x69_6969),
+    ("gray42", 0x6b_6b6b),
+    ("gray43", 0x6e_6e6e),
+    ("gray44", 0x70_7070),
+    ("gray45", 0x73_7373),
+    ("gray46", 0x75_7575),
+    ("gray47", 0x78_7878),
+    ("gray48", 0x7a_7a7a),
+    ("gray49", 0x7d_7d7d),
+    ("gray5", 0x0d_0d0d),
+    ("gray50", 0x7f_7f7f),
+    ("gray51", 0x82_8282),
+    ("gray52", 0x85_8585),
+    ("gray53", 0x87_8787),
+    ("gray54", 0x8a_8a8a),
+    ("gray55", 0x8c_8c8c),
+    ("gray56", 0x8f_8f8f),
+    ("gray57", 0x91_9191),
+    ("gray58", 0x94_9494),
+    ("gray59", 0x96_9696),
+    ("gray6", 0x0f_0f0f),
+    ("gray60", 0x99_9999),
+    ("gray61", 0x9c_9c9c),
+    ("gray62", 0x9e_9e9e),
+    ("gray63", 0xa1_a1a1),
+    ("gray64", 0xa3_a3a3),
+    ("gray65", 0xa6_a6a6),
+    ("gray66", 0xa8_a8a8),
+    ("gray67", 0xab_abab),
+    ("gray68", 0xad_adad),
+    ("gray69", 0xb0_b0b0),
+    ("gray7", 0x12_1212),
+    ("gray70", 0xb3_b3b3),
+    ("gray71", 0xb5_b5b5),
+    ("gray72", 0xb8_b8b8),
+    ("gray73", 0xba_baba),
+    ("gray74", 0xbd_bdbd),
+    ("gray75", 0xbf_bfbf),
+    ("gray76", 0xc2_c2c2),
+    ("gray77", 0xc4_c4c4),
+    ("gray78", 0xc7_c7c7),
+    ("gray79", 0xc9_c9c9),
+    ("gray8", 0x14_1414),
+    ("gray80", 0xcc_cccc),
+    ("gray81", 0xcf_cfcf),
+    ("gray82", 0xd1_d1d1),
+    ("gray83", 0xd4_d4d4),
+    ("gray84", 0xd6_d6d6),
+    ("gray85", 0xd9_d9d9),
+    ("gray86", 0xdb_dbdb),
+    ("gray87", 0xde_dede),
+    ("gray88", 0xe0_e0e0),
+    ("gray89", 0xe3_e3e3),
+    ("gray9", 0x17_1717),
+    ("gray90", 0xe5_e5e5),
+    ("gray91", 0xe8_e8e8),
+    ("gray92", 0xeb_ebeb),
+    ("gray93", 0xed_eded),
+    ("gray94", 0xf0_f0f0),
+    ("gray95", 0xf2_f2f2),
+    ("gray96", 0xf5_f5f5),
+    ("gray97", 0xf7_f7f7),
+    ("gray98", 0xfa_fafa),
+    ("gray99", 0xfc_fcfc),
+    ("green", 0x00_ff00),
+    ("green1", 0x00_ff00),
+    ("green2", 0x00_ee00),
+    ("green3", 0x00_cd00),
+    ("green4", 0x00_8b00),
+    ("greenyellow", 0xad_ff2f),
+    ("grey", 0xbe_bebe),
+    ("grey0", 0x00_0000),
+    ("grey1", 0x03_0303),
+    ("grey10", 0x1a_1a1a),
+    ("grey100", 0xff_ffff),
+    ("grey11", 0x1c_1c1c),
+    ("grey12", 0x1f_1f1f),
+    ("grey13", 0x21_2121),
+    ("grey14", 0x24_2424),
+    ("grey15", 0x26_2626),
+    ("grey16", 0x29_2929),
+    ("grey17", 0x2b_2b2b),
+    ("grey18", 0x2e_2e2e),
+    ("grey19", 0x30_3030),
+    ("grey2", 0x05_0505),
+    ("grey20", 0x33_3333),
+    ("grey21", 0x36_3636),
+    ("grey22", 0x38_3838),
+    ("grey23", 0x3b_3b3b),
+    ("grey24", 0x3d_3d3d),
+    ("grey25", 0x40_4040),
+    ("grey26", 0x42_4242),
+    ("grey27", 0x45_4545),
+    ("grey28", 0x47_4747),
+    ("grey29", 0x4a_4a4a),
+    ("grey3", 0x08_0808),
+    ("grey30", 0x4d_4d4d),
+    ("grey31", 0x4f_4f4f),
+    ("grey32", 0x52_5252),
+    ("grey33", 0x54_5454),
+    ("grey34", 0x57_5757),
+    ("grey35", 0x59_5959),
+    ("grey36", 0x5c_5c5c),
+    ("grey37", 0x5e_5e5e),
+    ("grey38", 0x61_6161),
+    ("grey39", 0x63_6363),
+    ("grey4", 0x0a_0a0a),
+    ("grey40", 0x66_6666),
+    ("grey41", 0x69_6969),
+    ("grey42", 0x6b_6b6b),
+    ("grey43", 0x6e_6e6e),
+    ("grey44", 0x70_7070),
+    ("grey45", 0x73_7373),
+    ("grey46", 0x75_7575),
+    ("grey47", 0x78_7878),
+    ("grey48", 0x7a_7a7a),
+    ("grey49", 0x7d_7d7d),
+    ("grey5", 0x0d_0d0d),
+    ("grey50", 0x7f_7f7f),
+    ("grey51", 0x82_8282),
+    ("grey52", 0x85_8585),
+    ("grey53", 0x87_8787),
+    ("grey54", 0x8a_8a8a),
+    ("grey55", 0x8c_8c8c),
+    ("grey56", 0x8f_8f8f),
+    ("grey57", 0x91_9191),
+    ("grey58", 0x94_9494),
+    ("grey59", 0x96_9696),
+    ("grey6", 0x0f_0f0f),
+    ("grey60", 0x99_9999),
+    ("grey61", 0x9c_9c9c),
+    ("grey62", 0x9e_9e9e),
+    ("grey63", 0xa1_a1a1),
+    ("grey64", 0xa3_a3a3),
+    ("grey65", 0xa6_a6a6),
+    ("grey66", 0xa8_a8a8),
+    ("grey67", 0xab_abab),
+    ("grey68", 0xad_adad),
+    ("grey69", 0xb0_b0b0),
+    ("grey7", 0x12_1212),
+    ("grey70", 0xb3_b3b3),
+    ("grey71", 0xb5_b5b5),
+    ("grey72", 0xb8_b8b8),
+    ("grey73", 0xba_baba),
+    ("grey74", 0xbd_bdbd),
+    ("grey75", 0xbf_bfbf),
+    ("grey76", 0xc2_c2c2),
+    ("grey77", 0xc4_c4c4),
+    ("grey78", 0xc7_c7c7),
+    ("grey79", 0xc9_c9c9),
+    ("grey8", 0x14_1414),
+    ("grey80", 0xcc_cccc),
+    ("grey81", 0xcf_cfcf),
+    ("grey82", 0xd1_d1d1),
+    ("grey83", 0xd4_d4d4),
+    ("grey84", 0xd6_d6d6),
+    ("grey85", 0xd9_d9d9),
+    ("grey86", 0xdb_dbdb),
+    ("grey87", 0xde_dede),
+    ("grey88", 0xe0_e0e0),
+    ("grey89", 0xe3_e3e3),
+    ("grey9", 0x17_1717),
+    ("grey90", 0xe5_e5e5),
+    ("grey91", 0xe8_e8e8),
+    ("grey92", 0xeb_ebeb),
+    ("grey93", 0xed_eded),
+    ("grey94", 0xf0_f0f0),
+    ("grey95", 0xf2_f2f2),
+    ("grey96", 0xf5_f5f5),
+    ("grey97", 0xf7_f7f7),
+    ("grey98", 0xfa_fafa),
+    ("grey99", 0xfc_fcfc),
+    ("honeydew", 0xf0_fff0),
+    ("honeydew1", 0xf0_fff0),
+    ("honeydew2", 0xe0_eee0),
+    ("honeydew3", 0xc1_cdc1),
+    ("honeydew4", 0x83_8b83),
+    ("hotpink", 0xff_69b4),
+    ("hotpink1", 0xff_6eb4),
+    ("hotpink2", 0xee_6aa7),
+    ("hotpink3", 0xcd_6090),
+    ("hotpink4", 0x8b_3a62),
+    ("indianred", 0xcd_5c5c),
+    ("indianred1", 0xff_6a6a),
+    ("indianred2", 0xee_6363),
+    ("indianred3", 0xcd_5555),
+    ("indianred4", 0x8b_3a3a),
+    ("indigo", 0x4b_0082),
+    ("ivory", 0xff_fff0),
+    ("ivory1", 0xff_fff0),
+    ("ivory2", 0xee_eee0),
+    ("ivory3", 0xcd_cdc1),
+    ("ivory4", 0x8b_8b83),
+    ("khaki", 0xf0_e68c),
+    ("khaki1", 0xff_f68f),
+    ("khaki2", 0xee_e685),
+    ("khaki3", 0xcd_c673),
+    ("khaki4", 0x8b_864e),
+    ("lavender", 0xe6_e6fa),
+    ("lavenderblush", 0xff_f0f5),
+    ("lavenderblush1", 0xff_f0f5),
+    ("lavenderblush2", 0xee_e0e5),
+    ("lavenderblush3", 0xcd_c1c5),
+    ("lavenderblush4", 0x8b_8386),
+    ("lawngreen", 0x7c_fc00),
+    ("lemonchiffon", 0xff_facd),
+    ("lemonchiffon1", 0xff_facd),
+    ("lemonchiffon2", 0xee_e9bf),
+    ("lemonchiffon3", 0xcd_c9a5),
+    ("lemonchiffon4", 0x8b_8970),
+    ("lightblue", 0xad_d8e6),
+    ("lightblue1", 0xbf_efff),
+    ("lightblue2", 0xb2_dfee),
+    ("lightblue3", 0x9a_c0cd),
+    ("lightblue4", 0x68_838b),
+    ("lightcoral", 0xf0_8080),
+    ("lightcyan", 0xe0_ffff),
+    ("lightcyan1", 0xe0_ffff),
+    ("lightcyan2", 0xd1_eeee),
+    ("lightcyan3", 0xb4_cdcd),
+    ("lightcyan4", 0x7a_8b8b),
+    ("lightgoldenrod", 0xee_dd82),
+    ("lightgoldenrod1", 0xff_ec8b),
+    ("lightgoldenrod2", 0xee_dc82),
+    ("lightgoldenrod3", 0xcd_be70),
+    ("lightgoldenrod4", 0x8b_814c),
+    ("lightgoldenrodyellow", 0xfa_fad2),
+    ("lightgray", 0xd3_d3d3),
+    ("lightgreen", 0x90_ee90),
+    ("lightgrey", 0xd3_d3d3),
+    ("lightmagenta", 0xff_bbff),
+    ("lightpink", 0xff_b6c1),
+    ("lightpink1", 0xff_aeb9),
+    ("lightpink2", 0xee_a2ad),
+    ("lightpink3", 0xcd_8c95),
+    ("lightpink4", 0x8b_5f65),
+    ("lightred", 0xff_bbbb),
+    ("lightsalmon", 0xff_a07a),
+    ("lightsalmon1", 0xff_a07a),
+    ("lightsalmon2", 0xee_9572),
+    ("lightsalmon3", 0xcd_8162),
+    ("lightsalmon4", 0x8b_5742),
+    ("lightseagreen", 0x20_b2aa),
+    ("lightskyblue", 0x87_cefa),
+    ("lightskyblue1", 0xb0_e2ff),
+    ("lightskyblue2", 0xa4_d3ee),
+    ("lightskyblue3", 0x8d_b6cd),
+    ("lightskyblue4", 0x60_7b8b),
+    ("lightslateblue", 0x84_70ff),
+    ("lightslategray", 0x77_8899),
+    ("lightslategrey", 0x77_8899),
+    ("lightsteelblue", 0xb0_c4de),
+    ("lightsteelblue1", 0xca_e1ff),
+    ("lightsteelblue2", 0xbc_d2ee),
+    ("lightsteelblue3", 0xa2_b5cd),
+    ("lightsteelblue4", 0x6e_7b8b),
+    ("lightyellow", 0xff_ffe0),
+    ("lightyellow1", 0xff_ffe0),
+    ("lightyellow2", 0xee_eed1),
+    ("lightyellow3", 0xcd_cdb4),
+    ("lightyellow4", 0x8b_8b7a),
+    ("lime", 0x00_ff00),
+    ("limegreen", 0x32_cd32),
+    ("linen", 0xfa_f0e6),
+    ("magenta", 0xff_00ff),
+    ("magenta1", 0xff_00ff),
+    ("magenta2", 0xee_00ee),
+    ("magenta3", 0xcd_00cd),
+    ("magenta4", 0x8b_008b),
+    ("maroon", 0xb0_3060),
+    ("maroon1", 0xff_34b3),
+    ("maroon2", 0xee_30a7),
+    ("maroon3", 0xcd_2990),
+    ("maroon4", 0x8b_1c62),
+    ("mediumaquamarine", 0x66_cdaa),
+    ("mediumblue", 0x00_00cd),
+    ("mediumorchid", 0xba_55d3),
+    ("mediumorchid1", 0xe0_66ff),
+    ("mediumorchid2", 0xd1_5fee),
+    ("mediumorchid3", 0xb4_52cd),
+    ("mediumorchid4", 0x7a_378b),
+    ("mediumpurple", 0x93_70db),
+    ("mediumpurple1", 0xab_82ff),
+    ("mediumpurple2", 0x9f_79ee),
+    ("mediumpurple3", 0x89_68cd),
+    ("mediumpurple4", 0x5d_478b),
+    ("mediumseagreen", 0x3c_b371),
+    ("mediumslateblue", 0x7b_68ee),
+    ("mediumspringgreen", 0x00_fa9a),
+    ("mediumturquoise", 0x48_d1cc),
+    ("mediumvioletred", 0xc7_1585),
+    ("midnightblue", 0x19_1970),
+    ("mintcream", 0xf5_fffa),
+    ("mistyrose", 0xff_e4e1),
+    ("mistyrose1", 0xff_e4e1),
+    ("mistyrose2", 0xee_d5d2),
+    ("mistyrose3", 0xcd_b7b5),
+    ("mistyrose4", 0x8b_7d7b),
+    ("moccasin", 0xff_e4b5),
+    ("navajowhite", 0xff_dead),
+    ("navajowhite1", 0xff_dead),
+    ("navajowhite2", 0xee_cfa1),
+    ("navajowhite3", 0xcd_b38b),
+    ("navajowhite4", 0x8b_795e),
+    ("navy", 0x00_0080),
+    ("navyblue", 0x00_0080),
+    ("nvimdarkblue", 0x00_4c73),
+    ("nvimdarkcyan", 0x00_7373),
+    ("nvimdarkgray1", 0x07_080d),
+    ("nvimdarkgray2", 0x14_161b),
+    ("nvimdarkgray3", 0x2c_2e33),
+    ("nvimdarkgray4", 0x4f_5258),
+    ("nvimdarkgreen", 0x00_5523),
+    ("nvimdarkgrey1", 0x07_080d),
+    ("nvimdarkgrey2", 0x14_161b),
+    ("nvimdarkgrey3", 0x2c_2e33),
+    ("nvimdarkgrey4", 0x4f_5258),
+    ("nvimdarkmagenta", 0x47_0045),
+    ("nvimdarkred", 0x59_0008),
+    ("nvimdarkyellow", 0x6b_5300),
+    ("nvimlightblue", 0xa6_dbff),
+    ("nvimlightcyan", 0x8c_f8f7),
+    ("nvimlightgray1", 0xee_f1f8),
+    ("nvimlightgray2", 0xe0_e2ea),
+    ("nvimlightgray3", 0xc4_c6cd),
+    ("nvimlightgray4", 0x9b_9ea4),
+    ("nvimlightgreen", 0xb3_f6c0),
+    ("nvimlightgrey1", 0xee_f1f8),
+    ("nvimlightgrey2", 0xe0_e2ea),
+    ("nvimlightgrey3", 0xc4_c6cd),
+    ("nvimlightgrey4", 0x9b_9ea4),
+    ("nvimlightmagenta", 0xff_caff),
+    ("nvimlightred", 0xff_c0b9),
+    ("nvimlightyellow", 0xfc_e094),
+    ("oldlace", 0xfd_f5e6),
+    ("olive", 0x80_8000),
+    ("olivedrab", 0x6b_8e23),
+    ("olivedrab1", 0xc0_ff3e),
+    ("olivedrab2", 0xb3_ee3a),
+    ("olivedrab3", 0x9a_cd32),
+    ("olivedrab4", 0x69_8b22),
+    ("orange", 0xff_a500),
+    ("orange1", 0xff_a500),
+    ("orange2", 0xee_9a00),
+    ("orange3", 0xcd_8500),
+    ("orange4", 0x8b_5a00),
+    ("orangered", 0xff_4500),
+    ("orangered1", 0xff_4500),
+    ("orangered2", 0xee_4000),
+    ("orangered3", 0xcd_3700),
+    ("orangered4", 0x8b_2500),
+    ("orchid", 0xda_70d6),
+    ("orchid1", 0xff_83fa),
+    ("orchid2", 0xee_7ae9),
+    ("orchid3", 0xcd_69c9),
+    ("orchid4", 0x8b_4789),
+    ("palegoldenrod", 0xee_e8aa),
+    ("palegreen", 0x98_fb98),
+    ("palegreen1", 0x9a_ff9a),
+    ("palegreen2", 0x90_ee90),
+    ("palegreen3", 0x7c_cd7c),
+    ("palegreen4", 0x54_8b54),
+    ("paleturquoise", 0xaf_eeee),
+    ("paleturquoise1", 0xbb_ffff),
+    ("paleturquoise2", 0xae_eeee),
+    ("paleturquoise3", 0x96_cdcd),
+    ("paleturquoise4", 0x66_8b8b),
+    ("palevioletred", 0xdb_7093),
+    ("palevioletred1", 0xff_82ab),
+    ("palevioletred2", 0xee_799f),
+    ("palevioletred3", 0xcd_6889),
+    ("palevioletred4", 0x8b_475d),
+    ("papayawhip", 0xff_efd5),
+    ("peachpuff", 0xff_dab9),
+    ("peachpuff1", 0xff_dab9),
+    ("peachpuff2", 0xee_cbad),
+    ("peachpuff3", 0xcd_af95),
+    ("peachpuff4", 0x8b_7765),
+    ("peru", 0xcd_853f),
+    ("pink", 0xff_c0cb),
+    ("pink1", 0xff_b5c5),
+    ("pink2", 0xee_a9b8),
+    ("pink3", 0xcd_919e),
+    ("pink4", 0x8b_636c),
+    ("plum", 0xdd_a0dd),
+    ("plum1", 0xff_bbff),
+    ("plum2", 0xee_aeee),
+    ("plum3", 0xcd_96cd),
+    ("plum4", 0x8b_668b),
+    ("powderblue", 0xb0_e0e6),
+    ("purple", 0xa0_20f0),
+    ("purple1", 0x9b_30ff),
+    ("purple2", 0x91_2cee),
+    ("purple3", 0x7d_26cd),
+    ("purple4", 0x55_1a8b),
+    ("rebeccapurple", 0x66_3399),
+    ("red", 0xff_0000),
+    ("red1", 0xff_0000),
+    ("red2", 0xee_0000),
+    ("red3", 0xcd_0000),
+    ("red4", 0x8b_0000),
+    ("rosybrown", 0xbc_8f8f),
+    ("rosybrown1", 0xff_c1c1),
+    ("rosybrown2", 0xee_b4b4),
+    ("rosybrown3", 0xcd_9b9b),
+    ("rosybrown4", 0x8b_6969),
+    ("royalblue", 0x41_69e1),
+    ("royalblue1", 0x48_76ff),
+    ("royalblue2", 0x43_6eee),
+    ("royalblue3", 0x3a_5fcd),
+    ("royalblue4", 0x27_408b),
+    ("saddlebrown", 0x8b_4513),
+    ("salmon", 0xfa_8072),
+    ("salmon1", 0xff_8c69),
+    ("salmon2", 0xee_8262),
+    ("salmon3", 0xcd_7054),
+    ("salmon4", 0x8b_4c39),
+    ("sandybrown", 0xf4_a460),
+    ("seagreen", 0x2e_8b57),
+    ("seagreen1", 0x54_ff9f),
+    ("seagreen2", 0x4e_ee94),
+    ("seagreen3", 0x43_cd80),
+    ("seagreen4", 0x2e_8b57),
+    ("seashell", 0xff_f5ee),
+    ("seashell1", 0xff_f5ee),
+    ("seashell2", 0xee_e5de),
+    ("seashell3", 0xcd_c5bf),
+    ("seashell4", 0x8b_8682),
+    ("sienna", 0xa0_522d),
+    ("sienna1", 0xff_8247),
+    ("sienna2", 0xee_7942),
+    ("sienna3", 0xcd_6839),
+    ("sienna4", 0x8b_4726),
+    ("silver", 0xc0_c0c0),
+    ("skyblue", 0x87_ceeb),
+    ("skyblue1", 0x87_ceff),
+    ("skyblue2", 0x7e_c0ee),
+    ("skyblue3", 0x6c_a6cd),
+    ("skyblue4", 0x4a_708b),
+    ("slateblue", 0x6a_5acd),
+    ("slateblue1", 0x83_6fff),
+    ("slateblue2", 0x7a_67ee),
+    ("slateblue3", 0x69_59cd),
+    ("slateblue4", 0x47_3c8b),
+    ("slategray", 0x70_8090),
+    ("slategray1", 0xc6_e2ff),
+    ("slategray2", 0xb9_d3ee),
+    ("slategray3", 0x9f_b6cd),
+    ("slategray4", 0x6c_7b8b),
+    ("slategrey", 0x70_8090),
+    ("snow", 0xff_fafa),
+    ("snow1", 0xff_fafa),
+    ("snow2", 0xee_e9e9),
+    ("snow3", 0xcd_c9c9),
+    ("snow4", 0x8b_8989),
+    ("springgreen", 0x00_ff7f),
+    ("springgreen1", 0x00_ff7f),
+    ("springgreen2", 0x00_ee76),
+    ("springgreen3", 0x00_cd66),
+    ("springgreen4", 0x00_8b45),
+    ("steelblue", 0x46_82b4),
+    ("steelblue1", 0x63_b8ff),
+    ("steelblue2", 0x5c_acee),
+    ("steelblue3", 0x4f_94cd),
+    ("steelblue4", 0x36_648b),
+    ("tan", 0xd2_b48c),
+    ("tan1", 0xff_a54f),
+    ("tan2", 0xee_9a49),
+    ("tan3", 0xcd_853f),
+    ("tan4", 0x8b_5a2b),
+    ("teal", 0x00_8080),
+    ("thistle", 0xd8_bfd8),
+    ("thistle1", 0xff_e1ff),
+    ("thistle2", 0xee_d2ee),
+    ("thistle3", 0xcd_b5cd),
+    ("thistle4", 0x8b_7b8b),
+    ("tomato", 0xff_6347),
+    ("tomato1", 0xff_6347),
+    ("tomato2", 0xee_5c42),
+    ("tomato3", 0xcd_4f39),
+    ("tomato4", 0x8b_3626),
+    ("turquoise", 0x40_e0d0),
+    ("turquoise1", 0x00_f5ff),
+    ("turquoise2", 0x00_e5ee),
+    ("turquoise3", 0x00_c5cd),
+    ("turquoise4", 0x00_868b),
+    ("violet", 0xee_82ee),
+    ("violetred", 0xd0_2090),
+    ("violetred1", 0xff_3e96),
+    ("violetred2", 0xee_3a8c),
+    ("violetred3", 0xcd_3278),
+    ("violetred4", 0x8b_2252),
+    ("webgray", 0x80_8080),
+    ("webgreen", 0x00_8000),
+    ("webgrey", 0x80_8080),
+    ("webmaroon", 0x80_0000),
+    ("webpurple", 0x80_0080),
+    ("wheat", 0xf5_deb3),
+    ("wheat1", 0xff_e7ba),
+    ("wheat2", 0xee_d8ae),
+    ("wheat3", 0xcd_ba96),
+    ("wheat4", 0x8b_7e66),
+    ("white", 0xff_ffff),
+    ("whitesmoke", 0xf5_f5f5),
+    ("yellow", 0xff_ff00),
+    ("yellow1", 0xff_ff00),
+    ("yellow2", 0xee_ee00),
+    ("yellow3", 0xcd_cd00),
+    ("yellow4", 0x8b_8b00),
+    ("yellowgreen", 0x9a_cd32),
+];
@@ -0,0 +1,491 @@
+//!
+//! This module contains the `Colorscheme` type, for defining a whole colorscheme in Rust.
+//!
+//! A `Colorscheme` can be applied directly (ex. from a `colors/<name>.lua` file that calls into a
+//! plugin), or written out as a standalone `colors/<name>.lua` file that doesn't need the plugin
+//! at all.
+//!
+//! ```no_run
+//! use overkill_nvim::{
+//!     color::Color,
+//!     colorscheme::{Colorscheme, Palette},
+//!     highlight::{Attributes, Highlight},
+//!     option::BackgroundValue,
+//! };
+//!
+//! let palette = Palette::default()
+//!     .with("fg", "#c0c0c0".parse().unwrap())
+//!     .with("bg", "#1c1c1c".parse().unwrap())
+//!     .with("comment", "Gray".parse().unwrap());
+//!
+//! let colorscheme = Colorscheme::new("meow", BackgroundValue::Dark)
+//!     .group(
+//!         "Normal",
+//!         Highlight::default().fg(palette["fg"]).bg(palette["bg"]),
+//!     )
+//!     .group(
+//!         "Comment",
+//!         Highlight::default()
+//!             .fg(palette["comment"])
+//!             .attributes(Attributes::default().italic()),
+//!     )
+//!     .link("SpecialComment", "Comment")
+//!     .unwrap()
+//!     .palette(palette);
+//!
+//! colorscheme.apply().unwrap();
+//! colorscheme.write_lua_file("/home/me/.config/nvim").unwrap();
+//! ```
+//!
+use crate::{
+    color::Color,
+    highlight::{Highlight, HighlightError},
+    option::{Background, BackgroundValue, NvimOptionError, StringOption},
+};
+use nvim_api::{nvim, sys::api::nvim::ObjectType, Dictionary, NvimString, Object};
+use std::{
+    ffi::NulError,
+    fmt::Write,
+    fs, io,
+    ops::Index,
+    path::{Path, PathBuf},
+};
+
+/// Error that happens when applying a `Colorscheme`.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ColorschemeError {
+    /// Setting one of the highlight groups failed.
+    ///
+    #[error("Unable to set highlight group '{}': {}", group, source)]
+    Highlight {
+        /// The name of the group that failed.
+        ///
+        group: String,
+
+        /// The underlying error.
+        ///
+        source: HighlightError,
+    },
+
+    /// The colorscheme's name contained a nul byte.
+    ///
+    #[error(transparent)]
+    NulError(#[from] NulError),
+
+    /// Setting `'background'` failed.
+    ///
+    #[error(transparent)]
+    OptionError(#[from] NvimOptionError),
+
+    /// Can occur if an error occurred in nvim when clearing highlights or setting variables.
+    ///
+    #[error(transparent)]
+    ApiError(#[from] nvim_api::Error),
+}
+
+/// Named colors that a `Colorscheme` is built from.
+///
+/// This is mostly for organizing the colors used in groups, but it's also kept on the `Colorscheme`
+/// so it can be looked up later (ex. by statusline code that wants to match the theme).
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<(String, Color)>,
+}
+
+impl Palette {
+    /// Adds (or replaces) the color called `name`.
+    ///
+    #[must_use]
+    pub fn with(self, name: &str, color: Color) -> Self {
+        let mut s = self;
+        s.set(name, color);
+        s
+    }
+
+    /// Adds (or replaces) the color called `name`.
+    ///
+    pub fn set(&mut self, name: &str, color: Color) {
+        match self.colors.iter_mut().find(|(n, _)| n == name) {
+            Some((_, c)) => *c = color,
+            None => self.colors.push((name.to_owned(), color)),
+        }
+    }
+
+    /// Gets the color called `name`.
+    ///
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.colors
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, color)| *color)
+    }
+
+    /// All of the colors, in the order they were added.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.colors
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+}
+
+impl Index<&str> for Palette {
+    type Output = Color;
+
+    /// # Panics
+    ///
+    /// This panics if there's no color called `name`.
+    ///
+    fn index(&self, name: &str) -> &Self::Output {
+        match self.colors.iter().find(|(n, _)| n == name) {
+            Some((_, color)) => color,
+            None => panic!("No color '{name}' in palette"),
+        }
+    }
+}
+
+/// A whole colorscheme: its highlight groups, the colors for `:terminal` buffers, and which
+/// `'background'` it's for.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Colorscheme {
+    name: String,
+    background: BackgroundValue,
+    palette: Palette,
+    terminal_colors: Option<[Color; 16]>,
+    groups: Vec<(String, Highlight)>,
+}
+
+impl Colorscheme {
+    /// Basic constructor. `name` should match the name of the `colors/<name>` file that loads
+    /// this colorscheme, since it's what `g:colors_name` gets set to.
+    ///
+    #[must_use]
+    pub fn new(name: &str, background: BackgroundValue) -> Self {
+        Self {
+            name: name.to_owned(),
+            background,
+            palette: Palette::default(),
+            terminal_colors: None,
+            groups: Vec::new(),
+        }
+    }
+
+    /// Sets the `Palette` the colorscheme is built from.
+    ///
+    #[must_use]
+    pub fn palette(self, palette: Palette) -> Self {
+        let mut s = self;
+        s.palette = palette;
+        s
+    }
+
+    /// Sets the colors to use for `g:terminal_color_0` through `g:terminal_color_15`.
+    ///
+    #[must_use]
+    pub const fn terminal_colors(self, colors: [Color; 16]) -> Self {
+        let mut s = self;
+        s.terminal_colors = Some(colors);
+        s
+    }
+
+    /// Adds (or replaces) the definition of highlight group `name`.
+    ///
+    #[must_use]
+    pub fn group(self, name: &str, highlight: Highlight) -> Self {
+        let mut s = self;
+
+        match s.groups.iter_mut().find(|(n, _)| n == name) {
+            Some((_, h)) => *h = highlight,
+            None => s.groups.push((name.to_owned(), highlight)),
+        }
+
+        s
+    }
+
+    /// Links highlight group `name` to `target`; shortcut for `group(name,
+    /// Highlight::link(target)?)`.
+    ///
+    /// # Errors
+    ///
+    /// * If `target` contains a nul byte.
+    ///
+    pub fn link(self, name: &str, target: &str) -> Result<Self, HighlightError> {
+        Ok(self.group(name, Highlight::link(target)?))
+    }
+
+    /// The colorscheme's name.
+    ///
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The `'background'` the colorscheme is for.
+    ///
+    #[must_use]
+    pub const fn background(&self) -> BackgroundValue {
+        self.background
+    }
+
+    /// The colors the colorscheme was built from.
+    ///
+    #[must_use]
+    pub const fn get_palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    /// Loads the colorscheme, like `:colorscheme` does: clears existing highlights, sets
+    /// `'background'`, `g:colors_name`, the terminal colors, then each highlight group.
+    ///
+    /// # Errors
+    ///
+    /// * If clearing highlights, setting `'background'`, or setting variables fails.
+    /// * If setting any of the highlight groups fails.
+    ///
+    pub fn apply(&self) -> Result<(), ColorschemeError> {
+        nvim::nvim_command("highlight clear")?;
+        nvim::nvim_command("if exists('syntax_on') | syntax reset | endif")?;
+
+        Background::set(self.background)?;
+        nvim::nvim_set_var(
+            "colors_name",
+            Object::from(NvimString::new(self.name.as_str())?),
+        )?;
+
+        if let Some(terminal_colors) = self.terminal_colors {
+            for (i, color) in terminal_colors.iter().enumerate() {
+                nvim::nvim_set_var(
+                    &format!("terminal_color_{i}"),
+                    Object::from(NvimString::from(*color)),
+                )?;
+            }
+        }
+
+        for (name, highlight) in &self.groups {
+            highlight
+                .set(name)
+                .map_err(|source| ColorschemeError::Highlight {
+                    group: name.clone(),
+                    source,
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates the source of a standalone `colors/<name>.lua` file that does the same thing as
+    /// `apply()`.
+    ///
+    #[must_use]
+    pub fn to_lua(&self) -> String {
+        let mut lua = String::new();
+
+        // Writing to a String never fails, so the results of `writeln!()` are ignored here.
+        let _ = writeln!(
+            lua,
+            "-- Generated by overkill_nvim; edit the source instead."
+        );
+        let _ = writeln!(lua);
+        let _ = writeln!(lua, "vim.cmd('highlight clear')");
+        let _ = writeln!(lua, "if vim.g.syntax_on then");
+        let _ = writeln!(lua, "    vim.cmd('syntax reset')");
+        let _ = writeln!(lua, "end");
+        let _ = writeln!(lua);
+        let _ = writeln!(lua, "vim.o.background = '{}'", self.background.as_str());
+        let _ = writeln!(lua, "vim.g.colors_name = {}", lua_string(&self.name));
+
+        if let Some(terminal_colors) = self.terminal_colors {
+            let _ = writeln!(lua);
+
+            for (i, color) in terminal_colors.iter().enumerate() {
+                let _ = writeln!(lua, "vim.g.terminal_color_{i} = '{color}'");
+            }
+        }
+
+        if !self.groups.is_empty() {
+            let _ = writeln!(lua);
+        }
+
+        for (name, highlight) in &self.groups {
+            let _ = writeln!(
+                lua,
+                "vim.api.nvim_set_hl(0, {}, {})",
+                lua_string(name),
+                lua_table(&Dictionary::from(highlight))
+            );
+        }
+
+        lua
+    }
+
+    /// Writes the output of `to_lua()` to `<dir>/colors/<name>.lua`, creating the `colors`
+    /// directory if needed. `dir` should be somewhere in `'runtimepath'`, like
+    /// `~/.config/nvim`. Returns the path to the file.
+    ///
+    /// # Errors
+    ///
+    /// * If the colorscheme's name can't be used as a file name that `:colorscheme` loads (it's
+    ///   empty, contains `/`, `\`, `..`, or whitespace); this is an `io::ErrorKind::InvalidInput`
+    ///   error.
+    /// * If creating the directory or writing the file fails.
+    ///
+    pub fn write_lua_file<P: AsRef<Path>>(&self, dir: P) -> io::Result<PathBuf> {
+        if !is_valid_file_name(&self.name) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid colorscheme name for a file: {:?}", self.name),
+            ));
+        }
+
+        let colors_dir = dir.as_ref().join("colors");
+        fs::create_dir_all(&colors_dir)?;
+
+        let path = colors_dir.join(format!("{}.lua", self.name));
+        fs::write(&path, self.to_lua())?;
+
+        Ok(path)
+    }
+}
+
+/// Can `name` be written to `colors/<name>.lua` (without escaping `colors/`) and then loaded with
+/// `:colorscheme <name>`?
+///
+fn is_valid_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.contains("..")
+        && !name.contains(|c: char| c == '/' || c == '\\' || c.is_whitespace() || c.is_control())
+}
+
+/// Keys in a `nvim_set_hl()` dictionary whose values are RGB colors.
+///
+const GUI_COLOR_KEYS: [&str; 3] = ["fg", "bg", "sp"];
+
+fn lua_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('\'');
+
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+
+    out.push('\'');
+    out
+}
+
+/// Converts `dict` to a Lua table literal. RGB colors are written as `'#rrggbb'` strings (which
+/// `nvim_set_hl()` also accepts) so the output is readable.
+///
+fn lua_table(dict: &Dictionary) -> String {
+    let entries = dict
+        .iter()
+        .map(|kv| {
+            let key = kv.key().to_string_lossy();
+            let value = kv.value();
+
+            let value = match value.try_as_integer() {
+                Ok(rgb) if GUI_COLOR_KEYS.contains(&key.as_ref()) => {
+                    // nvim only gives/takes 24-bit colors, so this won't truncate.
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let color = Color::from_rgb(rgb as u32);
+
+                    lua_string(&color.to_string())
+                }
+                _ => lua_value(value),
+            };
+
+            format!("{key} = {value}")
+        })
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        "{}".to_owned()
+    } else {
+        format!("{{ {} }}", entries.join(", "))
+    }
+}
+
+fn lua_value(value: &Object) -> String {
+    match value.object_type() {
+        ObjectType::kObjectTypeBoolean => value.as_boolean_unchecked().to_string(),
+        ObjectType::kObjectTypeInteger => value.as_integer_unchecked().to_string(),
+        ObjectType::kObjectTypeString => lua_string(&value.as_string_unchecked().to_string_lossy()),
+        ObjectType::kObjectTypeDictionary => lua_table(value.as_dictionary_unchecked()),
+        _ => "nil".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::Attributes;
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::default()
+            .with("fg", Color::new(1, 2, 3))
+            .with("bg", Color::new(4, 5, 6))
+            .with("fg", Color::new(7, 8, 9));
+
+        assert_eq!(palette.get("fg"), Some(Color::new(7, 8, 9)));
+        assert_eq!(palette["bg"], Color::new(4, 5, 6));
+        assert!(palette.get("meow").is_none());
+        assert_eq!(palette.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_to_lua() {
+        let mut terminal_colors = [Color::new(0, 0, 0); 16];
+        terminal_colors[15] = Color::new(255, 255, 255);
+
+        let colorscheme = Colorscheme::new("it's", BackgroundValue::Light)
+            .terminal_colors(terminal_colors)
+            .group(
+                "Normal",
+                Highlight::default()
+                    .fg(Color::new(255, 0, 0))
+                    .attributes(Attributes::default().bold())
+                    .cterm_attributes(Attributes::default().underline()),
+            )
+            .link("Meow", "Normal")
+            .unwrap();
+
+        let lua = colorscheme.to_lua();
+
+        assert!(lua.contains("vim.o.background = 'light'\n"));
+        assert!(lua.contains("vim.g.colors_name = 'it\\'s'\n"));
+        assert!(lua.contains("vim.g.terminal_color_0 = '#000000'\n"));
+        assert!(lua.contains("vim.g.terminal_color_15 = '#ffffff'\n"));
+        assert!(lua.contains(
+            "vim.api.nvim_set_hl(0, 'Normal', \
+            { fg = '#ff0000', ctermfg = 196, bold = true, cterm = { underline = true } })\n"
+        ));
+        assert!(lua.contains("vim.api.nvim_set_hl(0, 'Meow', { link = 'Normal' })\n"));
+    }
+
+    #[test]
+    fn test_write_lua_file_rejects_bad_names() {
+        let dir = std::env::temp_dir().join("overkill_colorscheme_test");
+
+        for name in ["", "../meow", "a/b", "a\\b", "..", "two words", "tab\t"] {
+            let error = Colorscheme::new(name, BackgroundValue::Dark)
+                .write_lua_file(&dir)
+                .unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{name:?}");
+        }
+
+        assert!(!dir.exists());
+
+        assert!(is_valid_file_name("gruvbox-material"));
+        assert!(is_valid_file_name("my.theme_2"));
+    }
+}
@@ -0,0 +1,166 @@
+//!
+//! This module contains functions for typing keys from Rust (see `:help feedkeys()`), and for
+//! converting keys to the codes that nvim uses for them internally.
+//!
+//! ```no_run
+//! use overkill_nvim::{
+//!     feedkeys::{feedkeys, FeedkeysMode},
+//!     key_code::KeyCode,
+//! };
+//!
+//! // Leave insert mode, without triggering mappings for <Esc>, right away.
+//! feedkeys(&[KeyCode::Esc], FeedkeysMode::NOREMAP | FeedkeysMode::EXECUTE).unwrap();
+//! ```
+//!
+use crate::key_code::{IntoNotation, KeyCode, KeySequence};
+use nvim_api::{nvim, Error, NvimString};
+use std::{fmt, ops::BitOr};
+
+/// The flags for how `feedkeys()` handles the keys it's given; these are the `mode` chars of
+/// `feedkeys()`. With none set, the keys are remapped, like with `FeedkeysMode::REMAP`.
+///
+/// Its `Display` is the `mode` string (ex. `nx`).
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FeedkeysMode(u8);
+
+impl FeedkeysMode {
+    /// No flags.
+    ///
+    pub const NONE: Self = Self(0);
+
+    /// `m`: Remap keys.
+    ///
+    pub const REMAP: Self = Self(1);
+
+    /// `n`: Don't remap keys.
+    ///
+    pub const NOREMAP: Self = Self(1 << 1);
+
+    /// `t`: Handle the keys as if they were typed; otherwise they're handled as if they come from
+    /// a mapping (which matters for undo, opening folds, etc.).
+    ///
+    pub const TYPED: Self = Self(1 << 2);
+
+    /// `i`: Insert the keys, instead of appending them.
+    ///
+    pub const INSERT: Self = Self(1 << 3);
+
+    /// `x`: Execute commands until the typeahead is empty.
+    ///
+    pub const EXECUTE: Self = Self(1 << 4);
+
+    /// `!`: With `EXECUTE`, don't end insert mode.
+    ///
+    pub const KEEP_INSERT: Self = Self(1 << 5);
+
+    /// `L`: Put the keys in the low-level input buffer, as if they came from the terminal.
+    ///
+    pub const LOW_LEVEL: Self = Self(1 << 6);
+
+    const ALL: [(Self, char); 7] = [
+        (Self::REMAP, 'm'),
+        (Self::NOREMAP, 'n'),
+        (Self::TYPED, 't'),
+        (Self::INSERT, 'i'),
+        (Self::EXECUTE, 'x'),
+        (Self::KEEP_INSERT, '!'),
+        (Self::LOW_LEVEL, 'L'),
+    ];
+
+    /// Are all of `other`'s flags in `self`?
+    ///
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The flags that are in `self`, `other`, or both.
+    ///
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOr for FeedkeysMode {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl fmt::Display for FeedkeysMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (flag, c) in Self::ALL {
+            if self.contains(flag) {
+                write!(f, "{c}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Types `keys`, as `mode` says to.
+///
+/// # Errors
+///
+/// * If nvim fails to handle the keys.
+///
+pub fn feedkeys(keys: &[KeyCode], mode: FeedkeysMode) -> Result<(), Error> {
+    let keys: String = keys.iter().map(ToString::to_string).collect();
+
+    feed(&keys, mode)
+}
+
+/// Like `feedkeys()`, but types all of the keys in `keys` (ex. `KeySequence::parse(":w<CR>")`).
+///
+/// # Errors
+///
+/// * If nvim fails to handle the keys.
+///
+pub fn feed_sequence(keys: &KeySequence, mode: FeedkeysMode) -> Result<(), Error> {
+    feed(&keys.to_string(), mode)
+}
+
+/// Converts `keys` to the codes that nvim uses for them internally (ex. `<CR>` to `"\r"`), which
+/// is what nvim's `feedkeys()` expects. `<Leader>` and `<LocalLeader>` are replaced with the
+/// leader keys.
+///
+/// # Errors
+///
+/// * If `keys` contains a nul byte.
+///
+pub fn replace_termcodes<K: IntoNotation>(keys: K) -> Result<NvimString, Error> {
+    nvim::nvim_replace_termcodes(&keys.into_notation(), true, true, true)
+}
+
+fn feed(keys: &str, mode: FeedkeysMode) -> Result<(), Error> {
+    // Typing nothing is a no-op, and nvim can't handle an empty string here.
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    // The codes from `replace_termcodes()` are already escaped.
+    nvim::nvim_feedkeys_replaced(replace_termcodes(keys)?, &mode.to_string(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_to_string() {
+        assert_eq!(FeedkeysMode::NONE.to_string(), "");
+        assert_eq!(
+            (FeedkeysMode::NOREMAP | FeedkeysMode::EXECUTE | FeedkeysMode::KEEP_INSERT).to_string(),
+            "nx!"
+        );
+        assert_eq!(
+            (FeedkeysMode::LOW_LEVEL | FeedkeysMode::REMAP | FeedkeysMode::TYPED).to_string(),
+            "mtL"
+        );
+    }
+}
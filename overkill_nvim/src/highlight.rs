@@ -0,0 +1,589 @@
+//!
+//! This module contains types for defining and getting highlight groups (see
+//! `:h highlight-groups`).
+//!
+//! ```no_run
+//! use overkill_nvim::{
+//!     color::Color,
+//!     highlight::{Attributes, Highlight},
+//! };
+//!
+//! let fg = "#c0c0c0".parse::<Color>().unwrap();
+//!
+//! Highlight::default()
+//!     .fg(fg)
+//!     .bg(fg.darken(0.8))
+//!     .attributes(Attributes::default().bold().italic())
+//!     .set("MyGroup")
+//!     .unwrap();
+//!
+//! Highlight::link("MyGroup").unwrap().set("MyOtherGroup").unwrap();
+//!
+//! // Derive a group from an existing one.
+//! let status_line = Highlight::get("StatusLine").unwrap();
+//!
+//! status_line
+//!     .clone()
+//!     .fg(status_line.get_fg().unwrap_or(fg).lighten(0.2))
+//!     .set("MyStatusLine")
+//!     .unwrap();
+//! ```
+//!
+use crate::color::Color;
+use nvim_api::{nvim, Dictionary, Integer, NvimString, Object};
+use std::{convert::TryFrom, ffi::NulError};
+
+/// Error that happens when defining highlight groups.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HighlightError {
+    /// A highlight group name (ex. for `link`) contained a nul byte.
+    ///
+    #[error(transparent)]
+    NulError(#[from] NulError),
+
+    /// A highlight definition from nvim had a value that wasn't what was expected. Really, this
+    /// should only happen if there's a bug in `overkill`.
+    ///
+    #[error("Unexpected value for highlight key '{}': {:?}", key, value)]
+    UnexpectedValue {
+        /// The key in the highlight definition.
+        ///
+        key: &'static str,
+
+        /// The value nvim returned for `key`.
+        ///
+        value: Object,
+    },
+
+    /// Can occur if an error occurred in nvim when setting or getting the highlight group.
+    ///
+    #[error(transparent)]
+    ApiError(#[from] nvim_api::Error),
+}
+
+/// Text attributes for a highlight group (see `:h attr-list`).
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Attributes {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    undercurl: bool,
+    strikethrough: bool,
+    reverse: bool,
+    standout: bool,
+    nocombine: bool,
+}
+
+macro_rules! def_attribute_meth {
+    ($field:ident) => {
+        #[doc=concat!("Turns on `", stringify!($field), "`.")]
+        #[must_use]
+        pub const fn $field(self) -> Self {
+            let mut s = self;
+            s.$field = true;
+            s
+        }
+    };
+}
+
+impl Attributes {
+    def_attribute_meth!(bold);
+    def_attribute_meth!(italic);
+    def_attribute_meth!(underline);
+    def_attribute_meth!(undercurl);
+    def_attribute_meth!(strikethrough);
+    def_attribute_meth!(reverse);
+    def_attribute_meth!(standout);
+    def_attribute_meth!(nocombine);
+
+    /// Each attribute, paired with the key nvim uses for it.
+    ///
+    const fn entries(self) -> [(&'static str, bool); 8] {
+        [
+            ("bold", self.bold),
+            ("italic", self.italic),
+            ("underline", self.underline),
+            ("undercurl", self.undercurl),
+            ("strikethrough", self.strikethrough),
+            ("reverse", self.reverse),
+            ("standout", self.standout),
+            ("nocombine", self.nocombine),
+        ]
+    }
+
+    fn write_to(self, dict: &mut Dictionary) {
+        for (key, value) in self.entries() {
+            if value {
+                dict.set(NvimString::new_unchecked(key), true);
+            }
+        }
+    }
+
+    /// Reads attributes from a `nvim_get_hl_by_*()` definition, which uses the same keys as
+    /// `nvim_set_hl()`.
+    ///
+    fn read_from(dict: &Dictionary) -> Self {
+        let is_set = |key: &str| dict.get(key).and_then(|v| v.try_as_boolean().ok()) == Some(true);
+
+        Self {
+            bold: is_set("bold"),
+            italic: is_set("italic"),
+            underline: is_set("underline"),
+            undercurl: is_set("undercurl"),
+            strikethrough: is_set("strikethrough"),
+            reverse: is_set("reverse"),
+            standout: is_set("standout"),
+            nocombine: is_set("nocombine"),
+        }
+    }
+}
+
+/// Definition of a highlight group, for use with `nvim_set_hl()`. Unset colors and attributes
+/// are left off, which is the same as `NONE` in `:highlight`.
+///
+/// By default, the `fg` and `bg` colors also set the cterm colors to their nearest xterm-256
+/// color, so the group works with and without `'termguicolors'`; `cterm_fg()` and `cterm_bg()`
+/// override that, and `derive_cterm(false)` turns it off.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Highlight {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    sp: Option<Color>,
+    cterm_fg: Option<u8>,
+    cterm_bg: Option<u8>,
+    derive_cterm: bool,
+    blend: Option<u8>,
+    attributes: Attributes,
+    cterm_attributes: Option<Attributes>,
+    link: Option<NvimString>,
+    default: bool,
+}
+
+impl Default for Highlight {
+    fn default() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            sp: None,
+            cterm_fg: None,
+            cterm_bg: None,
+            derive_cterm: true,
+            blend: None,
+            attributes: Attributes::default(),
+            cterm_attributes: None,
+            link: None,
+            default: false,
+        }
+    }
+}
+
+macro_rules! def_color_meth {
+    ($field:ident, $t:ty, $doc:expr) => {
+        #[doc=$doc]
+        #[must_use]
+        pub const fn $field(self, color: $t) -> Self {
+            let mut s = self;
+            s.$field = Some(color);
+            s
+        }
+    };
+}
+
+impl Highlight {
+    /// A highlight group that just links to `group`, like `:highlight link`.
+    ///
+    /// # Errors
+    ///
+    /// * If `group` contains a nul byte.
+    ///
+    pub fn link(group: &str) -> Result<Self, HighlightError> {
+        Ok(Self {
+            link: Some(NvimString::new(group)?),
+            ..Self::default()
+        })
+    }
+
+    def_color_meth!(fg, Color, "Sets the foreground color (`guifg`, and `ctermfg`).");
+    def_color_meth!(bg, Color, "Sets the background color (`guibg`, and `ctermbg`).");
+    def_color_meth!(
+        sp,
+        Color,
+        "Sets the GUI special color (`guisp`), used for undercurl and underline."
+    );
+    def_color_meth!(cterm_fg, u8, "Sets the cterm foreground color (`ctermfg`).");
+    def_color_meth!(cterm_bg, u8, "Sets the cterm background color (`ctermbg`).");
+    def_color_meth!(
+        blend,
+        u8,
+        "Sets the blend level (0-100) for floating windows and the popup menu."
+    );
+
+    /// Whether the cterm colors that aren't set explicitly are derived from `fg` and `bg`. When
+    /// this is off, an unset cterm color is left unset (`NONE`). It's on by default, and off for
+    /// highlights from `get()`.
+    ///
+    #[must_use]
+    pub const fn derive_cterm(self, derive: bool) -> Self {
+        let mut s = self;
+        s.derive_cterm = derive;
+        s
+    }
+
+    /// Sets the text attributes. Unless `cterm_attributes()` is also set, these apply to both the
+    /// GUI and cterm.
+    ///
+    #[must_use]
+    pub const fn attributes(self, attributes: Attributes) -> Self {
+        let mut s = self;
+        s.attributes = attributes;
+        s
+    }
+
+    /// Sets text attributes for cterm only (`cterm=`).
+    ///
+    #[must_use]
+    pub const fn cterm_attributes(self, attributes: Attributes) -> Self {
+        let mut s = self;
+        s.cterm_attributes = Some(attributes);
+        s
+    }
+
+    /// The GUI foreground color.
+    ///
+    #[must_use]
+    pub const fn get_fg(&self) -> Option<Color> {
+        self.fg
+    }
+
+    /// The GUI background color.
+    ///
+    #[must_use]
+    pub const fn get_bg(&self) -> Option<Color> {
+        self.bg
+    }
+
+    /// The GUI special color.
+    ///
+    #[must_use]
+    pub const fn get_sp(&self) -> Option<Color> {
+        self.sp
+    }
+
+    /// The cterm foreground color. If this wasn't set explicitly and `derive_cterm` is on, it's
+    /// the cterm color nearest to `get_fg()`, if that's set.
+    ///
+    #[must_use]
+    pub fn get_cterm_fg(&self) -> Option<u8> {
+        self.cterm_fg.or_else(|| self.derived(self.fg))
+    }
+
+    /// The cterm background color. If this wasn't set explicitly and `derive_cterm` is on, it's
+    /// the cterm color nearest to `get_bg()`, if that's set.
+    ///
+    #[must_use]
+    pub fn get_cterm_bg(&self) -> Option<u8> {
+        self.cterm_bg.or_else(|| self.derived(self.bg))
+    }
+
+    /// The blend level.
+    ///
+    #[must_use]
+    pub const fn get_blend(&self) -> Option<u8> {
+        self.blend
+    }
+
+    /// The text attributes.
+    ///
+    #[must_use]
+    pub const fn get_attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    /// The cterm text attributes; these are the same as `get_attributes()` unless
+    /// `cterm_attributes()` was set.
+    ///
+    #[must_use]
+    pub fn get_cterm_attributes(&self) -> Attributes {
+        self.cterm_attributes.unwrap_or(self.attributes)
+    }
+
+    /// Only set the highlight group if it doesn't already exist, like `:highlight default`.
+    /// (This isn't named `default()` to avoid clashing with `Default::default()`.)
+    ///
+    #[must_use]
+    pub const fn as_default(self) -> Self {
+        let mut s = self;
+        s.default = true;
+        s
+    }
+
+    /// Sets highlight group `name` to this definition.
+    ///
+    /// # Errors
+    ///
+    /// * If `name` contains a nul byte.
+    /// * If nvim fails to set the highlight group.
+    ///
+    pub fn set(&self, name: &str) -> Result<(), HighlightError> {
+        self.set_in_namespace(0, name)
+    }
+
+    /// Sets highlight group `name` to this definition, but only in namespace `ns_id`.
+    ///
+    /// # Errors
+    ///
+    /// * If `name` contains a nul byte.
+    /// * If nvim fails to set the highlight group.
+    ///
+    pub fn set_in_namespace(&self, ns_id: Integer, name: &str) -> Result<(), HighlightError> {
+        nvim::nvim_set_hl(ns_id, name, Dictionary::from(self))?;
+
+        Ok(())
+    }
+
+    /// Gets the current definition of highlight group `name`. Links are followed, so for a
+    /// linked group, this is the definition of the group it links to. The result has
+    /// `derive_cterm` off, so setting it (to any group) gives exactly the same colors and
+    /// attributes, and it's safe to get, modify, then set.
+    ///
+    /// # Errors
+    ///
+    /// * If `name` contains a nul byte.
+    /// * If there's no highlight group called `name`.
+    ///
+    pub fn get(name: &str) -> Result<Self, HighlightError> {
+        Self::from_definitions(
+            &nvim::nvim_get_hl_by_name(name, true)?,
+            &nvim::nvim_get_hl_by_name(name, false)?,
+        )
+    }
+
+    /// Same as `get()`, but gets the highlight group by its ID (see `hlID()`).
+    ///
+    /// # Errors
+    ///
+    /// * If `hl_id` isn't a valid highlight group ID.
+    ///
+    pub fn get_by_id(hl_id: Integer) -> Result<Self, HighlightError> {
+        Self::from_definitions(
+            &nvim::nvim_get_hl_by_id(hl_id, true)?,
+            &nvim::nvim_get_hl_by_id(hl_id, false)?,
+        )
+    }
+
+    /// Builds a `Highlight` from the `rgb` (GUI) and cterm definitions that
+    /// `nvim_get_hl_by_*()` return. cterm colors are taken as-is, with `derive_cterm` off, so an
+    /// unset cterm color stays unset when the result is set.
+    ///
+    fn from_definitions(rgb: &Dictionary, cterm: &Dictionary) -> Result<Self, HighlightError> {
+        let mut highlight = Self {
+            fg: get_gui_color(rgb, "foreground")?,
+            bg: get_gui_color(rgb, "background")?,
+            sp: get_gui_color(rgb, "special")?,
+            cterm_fg: get_cterm_color(cterm, "foreground")?,
+            cterm_bg: get_cterm_color(cterm, "background")?,
+            derive_cterm: false,
+            blend: get_cterm_color(rgb, "blend")?,
+            attributes: Attributes::read_from(rgb),
+            cterm_attributes: Some(Attributes::read_from(cterm)),
+            link: None,
+            default: false,
+        };
+
+        // With no `cterm` key, `nvim_set_hl()` uses the GUI attributes for cterm too.
+        if highlight.cterm_attributes == Some(highlight.attributes) {
+            highlight.cterm_attributes = None;
+        }
+
+        Ok(highlight)
+    }
+
+    fn derived(&self, color: Option<Color>) -> Option<u8> {
+        color.filter(|_| self.derive_cterm).map(Color::to_cterm)
+    }
+}
+
+fn get_gui_color(dict: &Dictionary, key: &'static str) -> Result<Option<Color>, HighlightError> {
+    dict.get(key)
+        .map(|value| {
+            value
+                .try_as_integer()
+                .ok()
+                .and_then(|i| u32::try_from(i).ok())
+                .map(Color::from_rgb)
+                .ok_or_else(|| HighlightError::UnexpectedValue {
+                    key,
+                    value: value.clone(),
+                })
+        })
+        .transpose()
+}
+
+fn get_cterm_color(dict: &Dictionary, key: &'static str) -> Result<Option<u8>, HighlightError> {
+    dict.get(key)
+        .map(|value| {
+            value
+                .try_as_integer()
+                .ok()
+                .and_then(|i| u8::try_from(i).ok())
+                .ok_or_else(|| HighlightError::UnexpectedValue {
+                    key,
+                    value: value.clone(),
+                })
+        })
+        .transpose()
+}
+
+impl From<&Highlight> for Dictionary {
+    fn from(value: &Highlight) -> Self {
+        let mut dict = Self::new();
+
+        // The cterm colors are written separately below, so they're only derived when that's on.
+        for (key, color) in [("fg", value.fg), ("bg", value.bg), ("sp", value.sp)] {
+            if let Some(color) = color {
+                dict.set(NvimString::new_unchecked(key), Integer::from(color.rgb()));
+            }
+        }
+
+        if let Some(cterm_fg) = value.get_cterm_fg() {
+            dict.set(NvimString::new_unchecked("ctermfg"), cterm_fg);
+        }
+
+        if let Some(cterm_bg) = value.get_cterm_bg() {
+            dict.set(NvimString::new_unchecked("ctermbg"), cterm_bg);
+        }
+
+        if let Some(blend) = value.blend {
+            dict.set(NvimString::new_unchecked("blend"), blend);
+        }
+
+        value.attributes.write_to(&mut dict);
+
+        if let Some(cterm_attributes) = value.cterm_attributes {
+            let mut cterm = Self::new();
+            cterm_attributes.write_to(&mut cterm);
+            dict.set(NvimString::new_unchecked("cterm"), cterm);
+        }
+
+        if let Some(ref link) = value.link {
+            dict.set(NvimString::new_unchecked("link"), link.clone());
+        }
+
+        if value.default {
+            dict.set(NvimString::new_unchecked("default"), true);
+        }
+
+        dict
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_into_dictionary() {
+        let highlight = Highlight::default()
+            .fg("#ff0000".parse().unwrap())
+            .cterm_fg(196)
+            .attributes(Attributes::default().bold().undercurl())
+            .cterm_attributes(Attributes::default().underline())
+            .as_default();
+
+        let dict = Dictionary::from(&highlight);
+
+        assert_eq!(dict.get_as_integer("fg"), Some(0x00ff_0000));
+        assert_eq!(dict.get_as_integer("ctermfg"), Some(196));
+        assert_eq!(dict.get_as_boolean("bold"), Some(true));
+        assert_eq!(dict.get_as_boolean("undercurl"), Some(true));
+        assert!(dict.get("italic").is_none());
+        assert!(dict.get("bg").is_none());
+        assert_eq!(
+            dict.get_as_dictionary("cterm")
+                .unwrap()
+                .get_as_boolean("underline"),
+            Some(true)
+        );
+        assert_eq!(dict.get_as_boolean("default"), Some(true));
+
+        let dict = Dictionary::from(&Highlight::default().bg(Color::new(0, 0, 255)));
+        assert_eq!(dict.get_as_integer("bg"), Some(0x0000_00ff));
+        assert_eq!(dict.get_as_integer("ctermbg"), Some(21));
+
+        let dict = Dictionary::from(&Highlight::link("Comment").unwrap());
+        assert_eq!(dict.len(), 1);
+        assert_eq!(
+            dict.get_as_string("link").unwrap(),
+            &NvimString::new_unchecked("Comment")
+        );
+
+        assert!(Highlight::link("Com\0ment").is_err());
+    }
+
+    #[test]
+    fn test_highlight_from_definitions() {
+        let mut rgb = Dictionary::new();
+        rgb.set(NvimString::new_unchecked("foreground"), 0x00ff_0000);
+        rgb.set(NvimString::new_unchecked("special"), 0x0000_00ff);
+        rgb.set(NvimString::new_unchecked("bold"), true);
+
+        let mut cterm = Dictionary::new();
+        cterm.set(NvimString::new_unchecked("foreground"), 196);
+        cterm.set(NvimString::new_unchecked("background"), 236);
+        cterm.set(NvimString::new_unchecked("bold"), true);
+
+        let highlight = Highlight::from_definitions(&rgb, &cterm).unwrap();
+
+        assert_eq!(
+            highlight,
+            Highlight::default()
+                .fg(Color::new(255, 0, 0))
+                .sp(Color::new(0, 0, 255))
+                .cterm_fg(196)
+                .cterm_bg(236)
+                .derive_cterm(false)
+                .attributes(Attributes::default().bold())
+        );
+        assert_eq!(highlight.get_cterm_fg(), Some(196));
+        assert_eq!(
+            highlight.get_cterm_attributes(),
+            Attributes::default().bold()
+        );
+
+        // A GUI color without a cterm color doesn't get one when it's set again.
+        let mut cterm_without_fg = Dictionary::new();
+        cterm_without_fg.set(NvimString::new_unchecked("bold"), true);
+        let highlight = Highlight::from_definitions(&rgb, &cterm_without_fg).unwrap();
+        let dict = Dictionary::from(&highlight);
+
+        assert_eq!(highlight.get_cterm_fg(), None);
+        assert_eq!(dict.get_as_integer("fg"), Some(0x00ff_0000));
+        assert!(dict.get("ctermfg").is_none());
+
+        rgb.set(NvimString::new_unchecked("standout"), true);
+        rgb.set(NvimString::new_unchecked("nocombine"), true);
+        rgb.set(NvimString::new_unchecked("blend"), 30);
+        let highlight = Highlight::from_definitions(&rgb, &cterm).unwrap();
+        let dict = Dictionary::from(&highlight);
+
+        assert_eq!(highlight.get_blend(), Some(30));
+        assert_eq!(dict.get_as_integer("blend"), Some(30));
+        assert_eq!(dict.get_as_boolean("standout"), Some(true));
+        assert_eq!(dict.get_as_boolean("nocombine"), Some(true));
+
+        cterm.set(NvimString::new_unchecked("underline"), true);
+        let highlight = Highlight::from_definitions(&rgb, &cterm).unwrap();
+
+        assert_eq!(
+            highlight.get_cterm_attributes(),
+            Attributes::default().bold().underline()
+        );
+
+        cterm.set(NvimString::new_unchecked("foreground"), 300);
+        assert!(Highlight::from_definitions(&rgb, &cterm).is_err());
+    }
+}
@@ -2,6 +2,10 @@
 
 //! Names for keys in mappings and such.
 //!
+pub mod key_sequence;
+
+pub use self::key_sequence::{BaseKey, IntoNotation, Key, KeySequence, Modifiers};
+
 use nvim_api::sys::api::nvim::NvimString;
 use std::{borrow::Borrow, fmt};
 
@@ -273,6 +277,42 @@ pub enum KeyCode {
     /// `<k9>`, keypad 9
     Keypad9,
 
+    /// `<Leader>`, the key in `g:mapleader` (`\` by default)
+    Leader,
+
+    /// `<LocalLeader>`, the key in `g:maplocalleader` (`\` by default)
+    LocalLeader,
+
+    /// `<Plug>`, for mappings that can't be typed (see `:help <Plug>`)
+    Plug,
+
+    /// `<SID>`, the script ID, for script-local mappings and functions
+    SID,
+
+    /// `<Cmd>`, runs the command that follows it, without changing modes
+    Cmd,
+
+    /// `<ScrollWheelUp>`, mouse wheel up
+    ScrollWheelUp,
+
+    /// `<ScrollWheelDown>`, mouse wheel down
+    ScrollWheelDown,
+
+    /// `<LeftMouse>`, left mouse button click
+    LeftMouse,
+
+    /// `<RightMouse>`, right mouse button click
+    RightMouse,
+
+    /// `<MiddleMouse>`, middle mouse button click
+    MiddleMouse,
+
+    /// `<LeftDrag>`, mouse drag with the left button down
+    LeftDrag,
+
+    /// `<2-LeftMouse>`, left mouse button double-click
+    DoubleLeftMouse,
+
     /// `<S-…>`, shift-key
     Shift(char),
 
@@ -289,7 +329,111 @@ pub enum KeyCode {
     Super(char),
 }
 
+impl KeyCode {
+    /// The keys that have a name of their own, without modifiers. `<lt>`, `<Space>`, `<Bar>` and
+    /// `<Bslash>` aren't here since, in a `KeySequence`, they're just chars.
+    ///
+    const NAMED: [Self; 78] = [
+        Self::Nul,
+        Self::BS,
+        Self::Tab,
+        Self::NL,
+        Self::FF,
+        Self::CR,
+        Self::Return,
+        Self::Enter,
+        Self::Esc,
+        Self::Del,
+        Self::CSI,
+        Self::XCSI,
+        Self::EOL,
+        Self::Ignore,
+        Self::NOP,
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::F1,
+        Self::F2,
+        Self::F3,
+        Self::F4,
+        Self::F5,
+        Self::F6,
+        Self::F7,
+        Self::F8,
+        Self::F9,
+        Self::F10,
+        Self::F11,
+        Self::F12,
+        Self::Help,
+        Self::Undo,
+        Self::Insert,
+        Self::Home,
+        Self::End,
+        Self::PageUp,
+        Self::PageDown,
+        Self::KeypadUp,
+        Self::KeypadDown,
+        Self::KeypadLeft,
+        Self::KeypadRight,
+        Self::KeypadHome,
+        Self::KeypadEnd,
+        Self::KeypadOrigin,
+        Self::KeypadPageUp,
+        Self::KeypadPageDown,
+        Self::KeypadDel,
+        Self::KeypadPlus,
+        Self::KeypadMinus,
+        Self::KeypadMultiply,
+        Self::KeypadDivide,
+        Self::KeypadPoint,
+        Self::KeypadComma,
+        Self::KeypadEqual,
+        Self::KeypadEnter,
+        Self::Keypad0,
+        Self::Keypad1,
+        Self::Keypad2,
+        Self::Keypad3,
+        Self::Keypad4,
+        Self::Keypad5,
+        Self::Keypad6,
+        Self::Keypad7,
+        Self::Keypad8,
+        Self::Keypad9,
+        Self::Leader,
+        Self::LocalLeader,
+        Self::Plug,
+        Self::SID,
+        Self::Cmd,
+        Self::ScrollWheelUp,
+        Self::ScrollWheelDown,
+        Self::LeftMouse,
+        Self::RightMouse,
+        Self::MiddleMouse,
+        Self::LeftDrag,
+        Self::DoubleLeftMouse,
+    ];
+
+    /// Looks up the key called `name` (ex. `CR` for `<CR>`), ignoring case like nvim does.
+    ///
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::NAMED
+            .iter()
+            .copied()
+            .find(|code| code.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The name of the key, without the `<>` (ex. `CR` for `<CR>`).
+    ///
+    pub(crate) fn name(self) -> String {
+        let s = self.to_string();
+
+        s[1..s.len() - 1].to_string()
+    }
+}
+
 impl fmt::Display for KeyCode {
+    #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
             Self::Nul => "<Nul>",
@@ -385,6 +529,20 @@ impl fmt::Display for KeyCode {
             Self::Keypad7 => "<k7>",
             Self::Keypad8 => "<k8>",
             Self::Keypad9 => "<k9>",
+
+            Self::Leader => "<Leader>",
+            Self::LocalLeader => "<LocalLeader>",
+            Self::Plug => "<Plug>",
+            Self::SID => "<SID>",
+            Self::Cmd => "<Cmd>",
+            Self::ScrollWheelUp => "<ScrollWheelUp>",
+            Self::ScrollWheelDown => "<ScrollWheelDown>",
+            Self::LeftMouse => "<LeftMouse>",
+            Self::RightMouse => "<RightMouse>",
+            Self::MiddleMouse => "<MiddleMouse>",
+            Self::LeftDrag => "<LeftDrag>",
+            Self::DoubleLeftMouse => "<2-LeftMouse>",
+
             Self::Shift(char) => return write!(f, "<S-{}>", char),
             Self::Control(char) => return write!(f, "<C-{}>", char),
             Self::Meta(char) => return write!(f, "<M-{}>", char),
@@ -497,6 +655,19 @@ impl TryFrom<NvimString> for KeyCode {
             "<k7>" => Self::Keypad7,
             "<k8>" => Self::Keypad8,
             "<k9>" => Self::Keypad9,
+
+            "<Leader>" => Self::Leader,
+            "<LocalLeader>" => Self::LocalLeader,
+            "<Plug>" => Self::Plug,
+            "<SID>" => Self::SID,
+            "<Cmd>" => Self::Cmd,
+            "<ScrollWheelUp>" => Self::ScrollWheelUp,
+            "<ScrollWheelDown>" => Self::ScrollWheelDown,
+            "<LeftMouse>" => Self::LeftMouse,
+            "<RightMouse>" => Self::RightMouse,
+            "<MiddleMouse>" => Self::MiddleMouse,
+            "<LeftDrag>" => Self::LeftDrag,
+            "<2-LeftMouse>" => Self::DoubleLeftMouse,
             _ => {
                 debug_assert!(s.starts_with('<'));
                 debug_assert!(s.ends_with('>'));
@@ -626,6 +797,19 @@ impl From<KeyCode> for NvimString {
             KeyCode::Keypad7 => "<k7>",
             KeyCode::Keypad8 => "<k8>",
             KeyCode::Keypad9 => "<k9>",
+
+            KeyCode::Leader => "<Leader>",
+            KeyCode::LocalLeader => "<LocalLeader>",
+            KeyCode::Plug => "<Plug>",
+            KeyCode::SID => "<SID>",
+            KeyCode::Cmd => "<Cmd>",
+            KeyCode::ScrollWheelUp => "<ScrollWheelUp>",
+            KeyCode::ScrollWheelDown => "<ScrollWheelDown>",
+            KeyCode::LeftMouse => "<LeftMouse>",
+            KeyCode::RightMouse => "<RightMouse>",
+            KeyCode::MiddleMouse => "<MiddleMouse>",
+            KeyCode::LeftDrag => "<LeftDrag>",
+            KeyCode::DoubleLeftMouse => "<2-LeftMouse>",
             KeyCode::Shift(char) => {
                 return Self::new(format!("<S-{}>", char)).unwrap();
             }
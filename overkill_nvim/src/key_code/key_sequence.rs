@@ -0,0 +1,720 @@
+//! This module contains `KeySequence`, for a whole series of keys in nvim's `<>` notation (ex. the
+//! lhs or rhs of a mapping), where each key can have any combination of modifiers.
+//!
+//! ```
+//! use overkill_nvim::key_code::{BaseKey, KeyCode, KeySequence, Modifiers};
+//!
+//! let keys = KeySequence::parse("<C-S-F5>a<lt>");
+//! assert_eq!(keys.len(), 3);
+//! assert_eq!(keys.keys()[0].modifiers(), Modifiers::CONTROL | Modifiers::SHIFT);
+//! assert_eq!(keys.keys()[0].base(), &BaseKey::Code(KeyCode::F5));
+//! assert_eq!(keys.keys()[2].base(), &BaseKey::Char('<'));
+//!
+//! assert_eq!(keys.to_string(), "<C-S-F5>a<lt>");
+//! ```
+//!
+use super::KeyCode;
+use crate::vimscript::{self, VimscriptError};
+use std::{fmt, ops::BitOr};
+
+/// A set of modifier keys.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    /// No modifiers.
+    ///
+    pub const NONE: Self = Self(0);
+
+    /// `S-`, shift
+    ///
+    pub const SHIFT: Self = Self(1);
+
+    /// `C-`, control
+    ///
+    pub const CONTROL: Self = Self(1 << 1);
+
+    /// `M-` or `A-`, alt or meta (nvim treats them as the same key)
+    ///
+    pub const META: Self = Self(1 << 2);
+
+    /// `D-`, command or "super"
+    ///
+    pub const SUPER: Self = Self(1 << 3);
+
+    /// `T-`, meta, where it's a different key from alt (`M-`)
+    ///
+    pub const T_META: Self = Self(1 << 4);
+
+    /// The modifiers in the order that nvim writes them in, with their letters.
+    ///
+    const ALL: [(Self, char); 5] = [
+        (Self::META, 'M'),
+        (Self::T_META, 'T'),
+        (Self::CONTROL, 'C'),
+        (Self::SHIFT, 'S'),
+        (Self::SUPER, 'D'),
+    ];
+
+    /// Are there no modifiers?
+    ///
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Are all of `other`'s modifiers in `self`?
+    ///
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The modifiers that are in `self`, `other`, or both.
+    ///
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// The modifier for the letter in `<X-...>` (ex. `C` for control), ignoring case.
+    ///
+    const fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'S' => Some(Self::SHIFT),
+            'C' => Some(Self::CONTROL),
+            'M' | 'A' => Some(Self::META),
+            'D' => Some(Self::SUPER),
+            'T' => Some(Self::T_META),
+            _ => None,
+        }
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+/// A key, without its modifiers.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BaseKey {
+    /// A key that types a char. `<lt>`, `<Space>`, `<Bar>` and `<Bslash>` are parsed to chars too.
+    ///
+    Char(char),
+
+    /// A key that has a name of its own, like `<CR>` or `<F5>`.
+    ///
+    Code(KeyCode),
+
+    /// A `<>` name that's not a `KeyCode` (ex. `<F13>`, `<ScrollWheelLeft>`), which is kept as it
+    /// was written, but lowercased, since nvim ignores case in them.
+    ///
+    Name(String),
+}
+
+/// A single key in a `KeySequence`: a `BaseKey`, with `Modifiers`.
+///
+/// Keys are normalized when they're built, so keys that nvim treats as the same compare equal:
+/// `<C-a>` is `<C-A>`, `<Return>` and `<Enter>` are `<CR>`, and `KeyCode`s that are a char or
+/// have a modifier (ex. `KeyCode::Space`, `KeyCode::Control('a')`, `KeyCode::ShiftUp`) are split
+/// into a `BaseKey` and `Modifiers`.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    modifiers: Modifiers,
+    base: BaseKey,
+}
+
+impl Key {
+    /// A key without modifiers.
+    ///
+    #[must_use]
+    pub fn new(base: BaseKey) -> Self {
+        Self::normalized(Modifiers::NONE, base)
+    }
+
+    /// Adds `modifiers` to the key.
+    ///
+    #[must_use]
+    pub fn with_modifiers(self, modifiers: Modifiers) -> Self {
+        Self::normalized(self.modifiers.union(modifiers), self.base)
+    }
+
+    /// The key's modifiers.
+    ///
+    #[must_use]
+    pub const fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// The key, without its modifiers.
+    ///
+    #[must_use]
+    pub const fn base(&self) -> &BaseKey {
+        &self.base
+    }
+
+    fn normalized(modifiers: Modifiers, base: BaseKey) -> Self {
+        let (modifiers, base) = match base {
+            BaseKey::Code(code) => {
+                let (code_modifiers, base) = split_code(code);
+
+                (modifiers.union(code_modifiers), base)
+            }
+            base => (modifiers, base),
+        };
+
+        // Control and a letter is the same control char, whatever the letter's case.
+        let base = match base {
+            BaseKey::Char(c)
+                if modifiers.contains(Modifiers::CONTROL) && c.is_ascii_alphabetic() =>
+            {
+                BaseKey::Char(c.to_ascii_uppercase())
+            }
+            base => base,
+        };
+
+        Self { modifiers, base }
+    }
+}
+
+impl From<char> for Key {
+    fn from(c: char) -> Self {
+        Self::new(BaseKey::Char(c))
+    }
+}
+
+impl From<KeyCode> for Key {
+    fn from(code: KeyCode) -> Self {
+        Self::new(BaseKey::Code(code))
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match &self.base {
+            BaseKey::Char(c) => match char_name(*c) {
+                Some(name) => name,
+                None if self.modifiers.is_empty() => return write!(f, "{c}"),
+                None => c.to_string(),
+            },
+            BaseKey::Code(code) => code.name(),
+            BaseKey::Name(name) => name.clone(),
+        };
+
+        f.write_str("<")?;
+
+        for (modifier, letter) in Modifiers::ALL {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{letter}-")?;
+            }
+        }
+
+        write!(f, "{name}>")
+    }
+}
+
+/// A series of keys, like the lhs or rhs of a mapping.
+///
+/// Parsing one never fails: like in nvim, anything that starts with `<` but isn't a key in `<>`
+/// notation is just the `<` char, followed by whatever comes after it.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KeySequence {
+    keys: Vec<Key>,
+}
+
+impl KeySequence {
+    /// An empty sequence.
+    ///
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { keys: Vec::new() }
+    }
+
+    /// Parses `s`, which is in nvim's `<>` notation (ex. `<leader>gd`, `<M-C-j>`, `a<lt>b`).
+    ///
+    #[must_use]
+    pub fn parse(s: &str) -> Self {
+        let mut keys = Vec::new();
+        let mut rest = s;
+
+        while let Some(c) = rest.chars().next() {
+            let (key, len) = parse_bracketed(rest).unwrap_or_else(|| (Key::from(c), c.len_utf8()));
+
+            keys.push(key);
+            rest = &rest[len..];
+        }
+
+        Self { keys }
+    }
+
+    /// The keys in the sequence.
+    ///
+    #[must_use]
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    /// The number of keys in the sequence.
+    ///
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Are there no keys in the sequence?
+    ///
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Adds `key` to the end of the sequence.
+    ///
+    pub fn push<K: Into<Key>>(&mut self, key: K) {
+        self.keys.push(key.into());
+    }
+
+    /// Replaces each `<Leader>` with `leader`, and each `<LocalLeader>` with `local_leader`.
+    ///
+    #[must_use]
+    pub fn with_leaders(&self, leader: &Self, local_leader: &Self) -> Self {
+        self.keys
+            .iter()
+            .flat_map(|key| match key.base {
+                BaseKey::Code(KeyCode::Leader) => leader.keys.clone(),
+                BaseKey::Code(KeyCode::LocalLeader) => local_leader.keys.clone(),
+                _ => vec![key.clone()],
+            })
+            .collect()
+    }
+
+    /// Like `with_leaders()`, using the keys in `g:mapleader` and `g:maplocalleader`, which is what
+    /// nvim does with the lhs when it defines a mapping. Like nvim, this uses `\` instead of a
+    /// leader that's not set, empty, or too long.
+    ///
+    /// # Errors
+    ///
+    /// * If getting `g:mapleader` or `g:maplocalleader` fails (ex. one isn't a string).
+    ///
+    pub fn resolve_leaders(&self) -> Result<Self, VimscriptError> {
+        let leader: String = vimscript::eval("get(g:, 'mapleader', '')")?;
+        let local_leader: String = vimscript::eval("get(g:, 'maplocalleader', '')")?;
+
+        Ok(self.with_leaders(&leader_keys(&leader), &leader_keys(&local_leader)))
+    }
+
+    /// Does the sequence start with all of `prefix`'s keys?
+    ///
+    #[must_use]
+    pub fn starts_with(&self, prefix: &Self) -> bool {
+        self.keys.starts_with(&prefix.keys)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for key in &self.keys {
+            write!(f, "{key}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for KeySequence {
+    fn from(s: &str) -> Self {
+        Self::parse(s)
+    }
+}
+
+impl From<Vec<Key>> for KeySequence {
+    fn from(keys: Vec<Key>) -> Self {
+        Self { keys }
+    }
+}
+
+impl FromIterator<Key> for KeySequence {
+    fn from_iter<I: IntoIterator<Item = Key>>(iter: I) -> Self {
+        Self {
+            keys: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// For types that can be used as a mapping's lhs or rhs: strings, which are taken to already be in
+/// `<>` notation, and the types from this module, which get written in it.
+///
+pub trait IntoNotation {
+    /// Converts `self` to `<>` notation.
+    ///
+    fn into_notation(self) -> String;
+}
+
+impl IntoNotation for &str {
+    fn into_notation(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoNotation for String {
+    fn into_notation(self) -> String {
+        self
+    }
+}
+
+impl IntoNotation for &String {
+    fn into_notation(self) -> String {
+        self.clone()
+    }
+}
+
+impl IntoNotation for &KeySequence {
+    fn into_notation(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoNotation for KeySequence {
+    fn into_notation(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoNotation for Key {
+    fn into_notation(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoNotation for KeyCode {
+    fn into_notation(self) -> String {
+        self.to_string()
+    }
+}
+
+/// The keys for the value of `g:mapleader` (or `g:maplocalleader`). The variables are used as
+/// they are, not as `<>` notation; like nvim's `replace_termcodes()`, `\` is used instead of an
+/// empty value, or one that's longer than 48 bytes.
+///
+fn leader_keys(value: &str) -> KeySequence {
+    const MAX_LEN: usize = 48;
+
+    if value.is_empty() || value.len() > MAX_LEN {
+        KeySequence::from(vec![Key::from('\\')])
+    } else {
+        value.chars().map(Key::from).collect()
+    }
+}
+
+/// The `<>` name for chars that can't be written as themselves in a mapping.
+///
+fn char_name(c: char) -> Option<String> {
+    let name = match c {
+        '<' => "lt",
+        ' ' => "Space",
+        '|' => "Bar",
+        '\\' => "Bslash",
+        _ => return None,
+    };
+
+    Some(name.to_string())
+}
+
+/// The char for `<>` names that are for chars (see `char_name()`).
+///
+fn char_from_name(name: &str) -> Option<char> {
+    ['<', ' ', '|', '\\']
+        .into_iter()
+        .find(|&c| char_name(c).is_some_and(|n| n.eq_ignore_ascii_case(name)))
+}
+
+/// Splits `code` into the `BaseKey` and `Modifiers` that nvim sees it as (ex. `<S-Up>` is `<Up>`
+/// with shift, `<Return>` is `<CR>`, and `<Space>` is the space char).
+///
+const fn split_code(code: KeyCode) -> (Modifiers, BaseKey) {
+    let (modifiers, code) = match code {
+        KeyCode::Return | KeyCode::Enter => (Modifiers::NONE, KeyCode::CR),
+        KeyCode::Space => return (Modifiers::NONE, BaseKey::Char(' ')),
+        KeyCode::LT => return (Modifiers::NONE, BaseKey::Char('<')),
+        KeyCode::Bar => return (Modifiers::NONE, BaseKey::Char('|')),
+        KeyCode::Bslash => return (Modifiers::NONE, BaseKey::Char('\\')),
+        KeyCode::Shift(c) => return (Modifiers::SHIFT, BaseKey::Char(c)),
+        KeyCode::Control(c) => return (Modifiers::CONTROL, BaseKey::Char(c)),
+        KeyCode::Meta(c) | KeyCode::Alt(c) => return (Modifiers::META, BaseKey::Char(c)),
+        KeyCode::Super(c) => return (Modifiers::SUPER, BaseKey::Char(c)),
+        KeyCode::ShiftUp => (Modifiers::SHIFT, KeyCode::Up),
+        KeyCode::ShiftDown => (Modifiers::SHIFT, KeyCode::Down),
+        KeyCode::ShiftLeft => (Modifiers::SHIFT, KeyCode::Left),
+        KeyCode::ShiftRight => (Modifiers::SHIFT, KeyCode::Right),
+        KeyCode::ControlLeft => (Modifiers::CONTROL, KeyCode::Left),
+        KeyCode::ControlRight => (Modifiers::CONTROL, KeyCode::Right),
+        KeyCode::ShiftF1 => (Modifiers::SHIFT, KeyCode::F1),
+        KeyCode::ShiftF2 => (Modifiers::SHIFT, KeyCode::F2),
+        KeyCode::ShiftF3 => (Modifiers::SHIFT, KeyCode::F3),
+        KeyCode::ShiftF4 => (Modifiers::SHIFT, KeyCode::F4),
+        KeyCode::ShiftF5 => (Modifiers::SHIFT, KeyCode::F5),
+        KeyCode::ShiftF6 => (Modifiers::SHIFT, KeyCode::F6),
+        KeyCode::ShiftF7 => (Modifiers::SHIFT, KeyCode::F7),
+        KeyCode::ShiftF8 => (Modifiers::SHIFT, KeyCode::F8),
+        KeyCode::ShiftF9 => (Modifiers::SHIFT, KeyCode::F9),
+        KeyCode::ShiftF10 => (Modifiers::SHIFT, KeyCode::F10),
+        KeyCode::ShiftF11 => (Modifiers::SHIFT, KeyCode::F11),
+        KeyCode::ShiftF12 => (Modifiers::SHIFT, KeyCode::F12),
+        code => (Modifiers::NONE, code),
+    };
+
+    (modifiers, BaseKey::Code(code))
+}
+
+/// The name to keep for a `BaseKey::Name`: lowercased, since nvim ignores case in them, except
+/// for function keys past `<F12>`, which are written like the others (ex. `<F13>`).
+///
+fn key_name(name: &str) -> String {
+    let name = name.to_lowercase();
+
+    match name.strip_prefix('f') {
+        Some(n) if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) => name.to_uppercase(),
+        _ => name,
+    }
+}
+
+/// Parses the `<>` key at the start of `s`, if there is one, returning it and the number of bytes
+/// it takes up.
+///
+fn parse_bracketed(s: &str) -> Option<(Key, usize)> {
+    let inner = s.strip_prefix('<')?;
+    let mut modifiers = Modifiers::NONE;
+    let mut rest = inner;
+
+    while let Some(modifier) = modifier_prefix(rest) {
+        modifiers = modifiers.union(modifier);
+        rest = &rest[2..];
+    }
+
+    // A single char, like the `j` in `<C-j>` (which only makes sense with a modifier).
+    let mut chars = rest.chars();
+    if let (Some(c), Some('>')) = (chars.next(), chars.next()) {
+        if !modifiers.is_empty() {
+            let len = s.len() - rest.len() + c.len_utf8() + 1;
+
+            return Some((Key::from(c).with_modifiers(modifiers), len));
+        }
+    }
+
+    let name_len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))?;
+    let name = &rest[..name_len];
+
+    if name.len() < 2 || name.ends_with('-') || !rest[name_len..].starts_with('>') {
+        return None;
+    }
+
+    let base = char_from_name(name).map_or_else(
+        || KeyCode::from_name(name).map_or_else(|| BaseKey::Name(key_name(name)), BaseKey::Code),
+        BaseKey::Char,
+    );
+
+    let len = s.len() - rest.len() + name_len + 1;
+
+    Some((Key::new(base).with_modifiers(modifiers), len))
+}
+
+/// The modifier for the `X-` at the start of `s`, if there's a key after it (so `<C-->` is
+/// control-minus, but `<C->` isn't a key at all).
+///
+fn modifier_prefix(s: &str) -> Option<Modifiers> {
+    let mut chars = s.chars();
+    let modifier = Modifiers::from_char(chars.next()?)?;
+
+    if chars.next()? != '-' {
+        return None;
+    }
+
+    let next = chars.next()?;
+
+    (next != '>' || chars.next() == Some('>')).then_some(modifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let keys = KeySequence::parse("<C-S-F5>");
+        assert_eq!(
+            keys.keys(),
+            [Key::from(KeyCode::F5).with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT)]
+        );
+
+        let keys = KeySequence::parse("<M-C-j><a-x><T-x>");
+        assert_eq!(
+            keys.keys(),
+            [
+                Key::from('j').with_modifiers(Modifiers::META | Modifiers::CONTROL),
+                Key::from('x').with_modifiers(Modifiers::META),
+                Key::from('x').with_modifiers(Modifiers::T_META),
+            ]
+        );
+
+        let keys = KeySequence::parse("<Leader>gd<cr>");
+        assert_eq!(
+            keys.keys(),
+            [
+                Key::from(KeyCode::Leader),
+                Key::from('g'),
+                Key::from('d'),
+                Key::from(KeyCode::CR),
+            ]
+        );
+
+        assert_eq!(
+            KeySequence::parse("a<lt>b<Space>").keys(),
+            [
+                Key::from('a'),
+                Key::from('<'),
+                Key::from('b'),
+                Key::from(' ')
+            ]
+        );
+        assert_eq!(
+            KeySequence::parse("<C-->").keys(),
+            [Key::from('-').with_modifiers(Modifiers::CONTROL)]
+        );
+        assert_eq!(
+            KeySequence::parse("<M->>").keys(),
+            [Key::from('>').with_modifiers(Modifiers::META)]
+        );
+
+        // Not keys in `<>` notation, so they're just chars.
+        for s in [
+            "<",
+            "<>",
+            "<a>",
+            "<C-",
+            "<C->",
+            "<not a key>",
+            "<F5",
+            "<é->",
+        ] {
+            let keys = KeySequence::parse(s);
+            assert_eq!(keys.len(), s.chars().count(), "{s}");
+            assert!(keys.keys().iter().all(|key| key.modifiers().is_empty()));
+        }
+
+        assert!(KeySequence::parse("").is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        for s in [
+            "<C-S-F5>",
+            "<M-C-J>",
+            "<M-T-x>",
+            "<Leader>gd",
+            "a<lt>b",
+            "<Plug>(foo)",
+            "<SID>Foo",
+            "<Cmd>w<CR>",
+            "<2-LeftMouse>",
+            "<S-ScrollWheelDown>",
+            "<C-Space>",
+            "<D-Left>",
+        ] {
+            assert_eq!(KeySequence::parse(s).to_string(), s);
+        }
+
+        assert_eq!(KeySequence::parse("<A-x>").to_string(), "<M-x>");
+        assert_eq!(KeySequence::parse("<F13>").to_string(), "<F13>");
+        assert_eq!(KeySequence::parse("<s-f37>").to_string(), "<S-F37>");
+        assert_eq!(
+            KeySequence::parse("<ScrollWheelLeft>").to_string(),
+            "<scrollwheelleft>"
+        );
+        assert_eq!(KeySequence::parse("<s-c-f5>").to_string(), "<C-S-F5>");
+        assert_eq!(
+            KeySequence::parse("a b|<").to_string(),
+            "a<Space>b<Bar><lt>"
+        );
+        assert_eq!(
+            KeySequence::parse("<not a key>").to_string(),
+            "<lt>not<Space>a<Space>key>"
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let same = |a: &str, b: &str| assert_eq!(KeySequence::parse(a), KeySequence::parse(b));
+
+        same("<C-a>", "<C-A>");
+        same("<M-C-j>", "<c-m-J>");
+        same("<CR>", "<Return>");
+        same("<CR>", "<enter>");
+        same("<S-Up>", "<s-up>");
+
+        assert_ne!(KeySequence::parse("<M-a>"), KeySequence::parse("<M-A>"));
+        assert_ne!(KeySequence::parse("<C-a>"), KeySequence::parse("<C-S-a>"));
+        assert_ne!(KeySequence::parse("<M-x>"), KeySequence::parse("<T-x>"));
+
+        let parsed = |s: &str| KeySequence::parse(s).keys()[0].clone();
+
+        assert_eq!(Key::from(KeyCode::Space), parsed("<Space>"));
+        assert_eq!(Key::from(KeyCode::LT), parsed("<lt>"));
+        assert_eq!(Key::from(KeyCode::Bar), parsed("|"));
+        assert_eq!(Key::from(KeyCode::Bslash), parsed("<Bslash>"));
+        assert_eq!(Key::from(KeyCode::Enter), parsed("<CR>"));
+        assert_eq!(Key::from(KeyCode::Control('a')), parsed("<C-A>"));
+        assert_eq!(Key::from(KeyCode::Shift('x')), parsed("<S-x>"));
+        assert_eq!(Key::from(KeyCode::Alt('x')), parsed("<M-x>"));
+        assert_eq!(Key::from(KeyCode::Super('x')), parsed("<D-x>"));
+        assert_eq!(Key::from(KeyCode::ShiftUp), parsed("<S-Up>"));
+        assert_eq!(Key::from(KeyCode::ControlRight), parsed("<C-Right>"));
+        assert_eq!(Key::from(KeyCode::ShiftF5), parsed("<S-F5>"));
+        assert_eq!(
+            Key::from('b').with_modifiers(Modifiers::CONTROL),
+            parsed("<C-B>")
+        );
+
+        assert_eq!(Key::from(KeyCode::Return).to_string(), "<CR>");
+        assert_eq!(Key::from(KeyCode::Control('b')).to_string(), "<C-B>");
+    }
+
+    #[test]
+    fn test_with_leaders() {
+        let keys = KeySequence::parse("<Leader>g<LocalLeader>");
+        let resolved = keys.with_leaders(&KeySequence::parse(" "), &KeySequence::parse(","));
+
+        assert_eq!(resolved.to_string(), "<Space>g,");
+        assert_eq!(
+            KeySequence::parse("x").with_leaders(&KeySequence::new(), &KeySequence::new()),
+            KeySequence::parse("x")
+        );
+    }
+
+    #[test]
+    fn test_leader_keys() {
+        assert_eq!(leader_keys(","), KeySequence::parse(","));
+        assert_eq!(leader_keys(" "), KeySequence::parse("<Space>"));
+        assert_eq!(leader_keys("<CR>").len(), 4);
+        assert_eq!(leader_keys(""), KeySequence::parse("<Bslash>"));
+        assert_eq!(leader_keys(&"x".repeat(48)).len(), 48);
+        assert_eq!(leader_keys(&"x".repeat(49)), KeySequence::parse("\\"));
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let keys = KeySequence::parse("<leader>gd");
+        assert_eq!(keys.keys()[0], Key::from(KeyCode::Leader));
+
+        assert!(keys.starts_with(&KeySequence::parse("<Leader>")));
+        assert!(keys.starts_with(&KeySequence::new()));
+        assert!(!keys.starts_with(&KeySequence::parse("<leader>x")));
+    }
+}
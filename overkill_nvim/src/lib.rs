@@ -20,10 +20,37 @@
     unused_qualifications
 )]
 
+/// Defines a builder method that turns on the `SpecialArguments` option `$field`, for types
+/// that keep theirs in an `arguments` field (`Mapper` and `Abbreviator`).
+///
+macro_rules! def_bool_return_self_meth {
+    ($field:ident) => {
+        /// Enable this option.
+        ///
+        #[must_use]
+        pub const fn $field(self) -> Self {
+            let arguments = self.arguments.$field();
+
+            let mut s = self;
+            s.arguments = arguments;
+            s
+        }
+    };
+}
+
+pub mod abbreviation;
+pub mod args;
+pub mod buffer;
+pub mod color;
+pub mod colorscheme;
+pub mod feedkeys;
+pub mod highlight;
 pub mod key_code;
+pub mod lua;
 pub mod mapping;
-// pub mod mode;
+pub mod mode;
 pub mod option;
+pub mod vimscript;
 
 #[cfg(feature = "lua_test")]
 pub mod lua_test;
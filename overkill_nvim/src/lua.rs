@@ -0,0 +1,70 @@
+//!
+//! This module contains functions for running Lua, for getting at things that are only
+//! available from Lua (ex. `vim.diagnostic`, `vim.lsp`, treesitter).
+//!
+//! ```no_run
+//! use overkill_nvim::lua;
+//!
+//! lua::exec("vim.notify(...)", ("Hello from Rust",)).unwrap();
+//!
+//! let count: usize = lua::call("vim.tbl_count", (vec![1, 2, 3],)).unwrap();
+//! let errors: Vec<nvim_api::Dictionary> =
+//!     lua::call("vim.diagnostic.get", (0,)).unwrap();
+//! ```
+//!
+use crate::args::IntoArgs;
+use nvim_api::{nvim, Error, Object};
+use std::convert::TryFrom;
+
+/// Executes Lua chunk `code` with `args`, which are available as `...` inside the chunk.
+///
+/// # Errors
+///
+/// * If `code` or any of `args` contain a nul byte.
+/// * If the chunk has a syntax error, or raises an error when it's run.
+///
+pub fn exec<A: IntoArgs>(code: &str, args: A) -> Result<(), Error> {
+    nvim::nvim_exec_lua(code, args.into_args()?)?;
+
+    Ok(())
+}
+
+/// Evaluates Lua chunk `code` with `args` (available as `...` inside the chunk), and converts the
+/// value it `return`s to `R`.
+///
+/// # Errors
+///
+/// * If `code` or any of `args` contain a nul byte.
+/// * If the chunk has a syntax error, or raises an error when it's run.
+/// * If the result can't be converted to `R`.
+///
+pub fn eval<R, A>(code: &str, args: A) -> Result<R, Error>
+where
+    R: TryFrom<Object>,
+    Error: From<R::Error>,
+    A: IntoArgs,
+{
+    let object = nvim::nvim_exec_lua(code, args.into_args()?)?;
+
+    Ok(R::try_from(object)?)
+}
+
+/// Calls Lua function `function` with `args`, and converts the (first) value it returns to `R`.
+///
+/// `function` can be any Lua expression that evaluates to a function, like `vim.fn.expand` or
+/// `require('foo').bar`.
+///
+/// # Errors
+///
+/// * If `function` or any of `args` contain a nul byte.
+/// * If `function` isn't a function, or raises an error when it's called.
+/// * If the result can't be converted to `R`.
+///
+pub fn call<R, A>(function: &str, args: A) -> Result<R, Error>
+where
+    R: TryFrom<Object>,
+    Error: From<R::Error>,
+    A: IntoArgs,
+{
+    eval(&format!("return ({function})(...)"), args)
+}
@@ -84,6 +84,210 @@ fn test_overkill_set_sub_assign_global_nullable_string_char_flags_option() {
     assert_eq!(expected, value);
 }
 
+mod buffer {
+    use crate::buffer::{Buffer, DeleteOptions};
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_buffer() {
+        let buffer = Buffer::create(false, true).unwrap();
+
+        assert!(Buffer::list().contains(&buffer));
+        assert_ne!(buffer, Buffer::current());
+        assert!(buffer.is_valid());
+
+        buffer.set_lines(.., &["one", "two"]).unwrap();
+        assert_eq!(buffer.line_count().unwrap(), 2);
+        assert_eq!(buffer.lines(-1..).unwrap(), vec!["two"]);
+
+        buffer.delete(DeleteOptions::default().force()).unwrap();
+        assert!(!buffer.is_valid());
+    }
+
+    #[nvim_test]
+    fn test_buffer_name() {
+        let buffer = Buffer::create(false, true).unwrap();
+        assert_eq!(buffer.name().unwrap(), "");
+
+        buffer.set_name("overkill_test_buffer").unwrap();
+        assert!(buffer.name().unwrap().ends_with("overkill_test_buffer"));
+
+        buffer.delete(DeleteOptions::default().force()).unwrap();
+    }
+}
+
+mod highlight {
+    use crate::highlight::{Attributes, Highlight};
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_highlight_set() {
+        Highlight::default()
+            .fg("#c0ffee".parse().unwrap())
+            .bg("#000000".parse().unwrap())
+            .cterm_fg(123)
+            .attributes(Attributes::default().bold().italic())
+            .set("OverkillTestHighlight")
+            .unwrap();
+
+        Highlight::link("OverkillTestHighlight")
+            .unwrap()
+            .as_default()
+            .set("OverkillTestHighlightLink")
+            .unwrap();
+
+        assert!(Highlight::default().set("Overkill\0Test").is_err());
+    }
+
+    #[nvim_test]
+    fn test_highlight_get() {
+        let highlight = Highlight::default()
+            .fg("#c0ffee".parse().unwrap())
+            .sp("#ff0000".parse().unwrap())
+            .cterm_bg(236)
+            .attributes(Attributes::default().bold())
+            .cterm_attributes(Attributes::default().underline());
+        highlight.set("OverkillTestHighlightGet").unwrap();
+
+        let gotten = Highlight::get("OverkillTestHighlightGet").unwrap();
+        assert_eq!(gotten, highlight);
+
+        Highlight::link("OverkillTestHighlightGet")
+            .unwrap()
+            .set("OverkillTestHighlightGetLink")
+            .unwrap();
+        assert_eq!(
+            Highlight::get("OverkillTestHighlightGetLink").unwrap(),
+            highlight
+        );
+
+        assert!(Highlight::get("OverkillTestNotAGroup").is_err());
+    }
+}
+
+mod colorscheme {
+    use crate::{
+        color::Color,
+        colorscheme::Colorscheme,
+        highlight::Highlight,
+        option::{Background, BackgroundValue, StringOption},
+    };
+    use nvim_api::nvim;
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_colorscheme_apply() {
+        Colorscheme::new("overkill_test", BackgroundValue::Light)
+            .terminal_colors([Color::new(0, 0, 0); 16])
+            .group("Normal", Highlight::default().fg(Color::new(0, 0, 0)))
+            .link("OverkillTestColorschemeLink", "Normal")
+            .unwrap()
+            .apply()
+            .unwrap();
+
+        assert_eq!(Background::get().unwrap(), BackgroundValue::Light);
+        assert_eq!(
+            nvim::nvim_get_var("colors_name")
+                .unwrap()
+                .try_as_string()
+                .unwrap()
+                .to_string_lossy(),
+            "overkill_test"
+        );
+        assert_eq!(
+            nvim::nvim_get_var("terminal_color_15")
+                .unwrap()
+                .try_as_string()
+                .unwrap()
+                .to_string_lossy(),
+            "#000000"
+        );
+    }
+}
+
+mod vimscript {
+    use crate::vimscript::{call, eval, exec_block, exec_output, VimscriptError};
+    use nvim_api::Dictionary;
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_exec_block() {
+        assert_eq!(exec_output("echo 'meow'").unwrap(), "meow");
+
+        let src = "let g:overkill_test_exec_block = 1\necho 'meow'\nNotARealCommand\necho 'nope'";
+
+        match exec_block(src, true) {
+            Err(VimscriptError::Exception { line, exception }) => {
+                assert_eq!(line, Some(3));
+                assert_eq!(exception.code(), Some(492));
+            }
+            other => panic!("Expected an exception, got {other:?}"),
+        }
+
+        assert_eq!(
+            exec_block("echo 'meow'", true).unwrap(),
+            Some("meow".to_string())
+        );
+        assert_eq!(exec_block("echo 'meow'", false).unwrap(), None);
+    }
+
+    #[nvim_test]
+    fn test_exec_output_silent() {
+        assert_eq!(exec_output("let x = 1").unwrap(), "");
+        assert_eq!(exec_block("let x = 2", true).unwrap(), Some(String::new()));
+    }
+
+    #[nvim_test]
+    fn test_call() {
+        assert_eq!(call::<String, _>("toupper", ("meow",)).unwrap(), "MEOW");
+        assert_eq!(call::<i64, _>("max", (vec![1, 5, 3],)).unwrap(), 5);
+        assert_eq!(
+            call::<Vec<String>, _>("split", ("a,b", ",")).unwrap(),
+            vec!["a", "b"]
+        );
+        assert!(call::<i64, _>("toupper", ("meow",)).is_err());
+
+        assert_eq!(eval::<u8>("1 + 2").unwrap(), 3);
+        assert!(eval::<bool>("v:true").unwrap());
+        assert_eq!(
+            eval::<Dictionary>("{'a': 1}").unwrap().get_as_integer("a"),
+            Some(1)
+        );
+        assert!(matches!(
+            eval::<i64>("1 +"),
+            Err(VimscriptError::Exception { .. })
+        ));
+    }
+}
+
+mod lua {
+    use crate::lua;
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_lua_call() {
+        assert_eq!(
+            lua::call::<usize, _>("vim.tbl_count", (vec![1, 2, 3],)).unwrap(),
+            3
+        );
+        assert_eq!(
+            lua::call::<String, _>("string.rep", ("ab", 2)).unwrap(),
+            "abab"
+        );
+        assert_eq!(
+            lua::call::<Vec<String>, _>("vim.split", ("a,b", ",")).unwrap(),
+            vec!["a", "b"]
+        );
+        assert!(lua::call::<String, _>("not_a_function", ()).is_err());
+
+        lua::exec("vim.g.overkill_test_lua_exec = ...", (42,)).unwrap();
+        assert_eq!(
+            lua::eval::<i64, _>("return vim.g.overkill_test_lua_exec", ()).unwrap(),
+            42
+        );
+    }
+}
+
 mod mapping {
     use super::*;
     use crate::mapping::{mapper::Mapper, MapMode};
@@ -91,10 +295,11 @@ mod mapping {
     macro_rules! test_map {
         ($mode:ident, $lhs:expr) => {
             let mapper = Mapper::new(MapMode::$mode);
-            mapper.map($lhs, "<NOP>");
+            mapper.map($lhs, "<NOP>").unwrap();
 
             let list = mapper
                 .list()
+                .unwrap()
                 .into_iter()
                 .find(|mapping| mapping.lhs() == $lhs);
 
@@ -105,10 +310,11 @@ mod mapping {
     macro_rules! test_map_with_options {
         ($mode:ident, $lhs:expr) => {
             let mapper = Mapper::new(MapMode::$mode).nowait().silent().unique();
-            mapper.map($lhs, "<NOP>");
+            mapper.map($lhs, "<NOP>").unwrap();
 
             let list = mapper
                 .list()
+                .unwrap()
                 .into_iter()
                 .find(|mapping| mapping.lhs() == $lhs);
 
@@ -182,4 +388,254 @@ mod mapping {
         test_map!(TerminalJob, "<C-A>t1");
         test_map_with_options!(TerminalJob, "<C-A>t2");
     }
+
+    #[nvim_test]
+    fn test_map_fn() {
+        use crate::{buffer::Buffer, vimscript};
+        use std::{cell::Cell, rc::Rc};
+
+        let count = Rc::new(Cell::new(0));
+        let mapper = Mapper::new(MapMode::Normal);
+
+        let global_count = Rc::clone(&count);
+        mapper
+            .map_fn("<C-A>f1", move || global_count.set(global_count.get() + 1))
+            .unwrap();
+
+        let buffer_count = Rc::clone(&count);
+        mapper
+            .buf_map_fn(Buffer::current(), "<C-A>f2", move || {
+                buffer_count.set(buffer_count.get() + 10);
+            })
+            .unwrap();
+
+        vimscript::exec(r#"execute "normal \<C-A>f1\<C-A>f2\<C-A>f1""#).unwrap();
+        assert_eq!(count.get(), 12);
+
+        assert!(mapper
+            .list_all()
+            .unwrap()
+            .into_iter()
+            .any(|mapping| mapping.lhs() == "<C-A>f1"));
+    }
+
+    #[nvim_test]
+    fn test_unmap() {
+        use crate::buffer::Buffer;
+        use nvim_api::keymap::Error;
+
+        let has_mapping =
+            |mapper: &Mapper, lhs: &str| mapper.list_all().unwrap().iter().any(|m| m.lhs() == lhs);
+
+        let mapper = Mapper::new(MapMode::Normal);
+        mapper.map("<C-A>u1", "<NOP>").unwrap();
+        mapper.unmap("<C-A>u1").unwrap();
+        assert!(!has_mapping(&mapper, "<C-A>u1"));
+        assert!(matches!(mapper.unmap("<C-A>u1"), Err(Error::NoMatches(_))));
+
+        let buffer = Buffer::current();
+        mapper.buf_map(buffer, "<C-A>u2", "<NOP>").unwrap();
+        mapper.buf_unmap(buffer, "<C-A>u2").unwrap();
+        assert!(matches!(
+            mapper.buf_unmap(buffer, "<C-A>u2"),
+            Err(Error::NoMatches(_))
+        ));
+
+        mapper.map("<C-A>u3", "<NOP>").unwrap();
+        mapper.buf_map(buffer, "<C-A>u4", "<NOP>").unwrap();
+        mapper.clear().unwrap();
+        assert!(!has_mapping(&mapper, "<C-A>u3"));
+        assert!(has_mapping(&mapper, "<C-A>u4"));
+
+        mapper.buf_clear(buffer).unwrap();
+        assert!(!has_mapping(&mapper, "<C-A>u4"));
+    }
+
+    #[nvim_test]
+    fn test_list_merged() {
+        use crate::{buffer::Buffer, mapping::Mapping};
+
+        let buffer = Buffer::current();
+        let mapper = Mapper::new(MapMode::Normal);
+        mapper.map("<C-A>m1", "<NOP>").unwrap();
+        mapper.map("<C-A>m2", "<NOP>").unwrap();
+        mapper.buf_map(buffer, "<C-A>m2", "<NOP>").unwrap();
+
+        let buffer_mappings = mapper.list_for_buffer(buffer).unwrap();
+        assert!(buffer_mappings.iter().all(Mapping::buffer));
+        assert!(buffer_mappings.iter().any(|m| m.lhs() == "<C-A>m2"));
+
+        let merged = mapper.list_merged(buffer).unwrap();
+        let find = |lhs: &str| merged.iter().find(|m| m.lhs() == lhs).unwrap();
+        assert!(!find("<C-A>m1").is_shadowing());
+        assert!(find("<C-A>m2").is_shadowing());
+        assert!(find("<C-A>m2").effective().buffer());
+    }
+
+    #[nvim_test]
+    fn test_desc_and_registry() {
+        use crate::{buffer::Buffer, mapping::registry};
+
+        let mapper = Mapper::new(MapMode::Normal);
+        mapper.name_group("<C-B>g", "group");
+        mapper.clone().desc("First").noremap("<C-B>g1", "<NOP>").unwrap();
+        mapper.clone().desc("Second").map_fn("<C-B>g2", || {}).unwrap();
+        mapper.noremap("<C-B>x", "<NOP>").unwrap();
+
+        let list = mapper.list_all().unwrap();
+        let mapping = list.iter().find(|m| m.lhs() == "<C-B>g1").unwrap();
+        assert_eq!(mapping.desc(), Some("First"));
+        assert!(mapping.noremap());
+
+        let tree = registry::continuations(MapMode::Normal, "<C-B>");
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].label(), Some("group"));
+        assert_eq!(tree[0].children().len(), 2);
+        assert_eq!(tree[0].children()[1].desc(), Some("Second"));
+        assert_eq!(tree[1].lhs().to_string(), "<C-B>x");
+        assert_eq!(tree[1].desc(), None);
+
+        mapper.unmap("<C-B>x").unwrap();
+        assert_eq!(registry::continuations(MapMode::Normal, "<C-B>").len(), 1);
+        assert_eq!(registry::continuations(MapMode::Normal, "<C-b>").len(), 1);
+
+        let buffer = Buffer::current();
+        let desc = format!("Buffer {}", buffer.handle().handle());
+        mapper
+            .clone()
+            .desc(&desc)
+            .buf_noremap(buffer, "<C-B>b", "<NOP>")
+            .unwrap();
+        assert_eq!(registry::continuations(MapMode::Normal, "<C-B>").len(), 1);
+
+        let tree = registry::buf_continuations(buffer, MapMode::Normal, "<C-B>");
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].desc(), Some(desc.as_str()));
+
+        Mapper::new(MapMode::NormalVisualSelectOperatorPending)
+            .noremap("<C-B>o", "<NOP>")
+            .unwrap();
+        mapper.unmap("<C-B>o").unwrap();
+        assert_eq!(registry::continuations(MapMode::Normal, "<C-B>").len(), 1);
+        assert_eq!(registry::continuations(MapMode::Visual, "<C-B>").len(), 1);
+    }
+
+    #[nvim_test]
+    fn test_map_in() {
+        use crate::mapping::MapModes;
+        use nvim_api::keymap::Error;
+
+        let mapper = Mapper::new(MapMode::Normal);
+        let modes = MapModes::NORMAL | MapModes::VISUAL | MapModes::OPERATOR_PENDING;
+        mapper.noremap_in(modes, "<C-A>i1", "<NOP>").unwrap();
+
+        let mapping = Mapper::new(MapMode::Visual)
+            .list_all()
+            .unwrap()
+            .into_iter()
+            .find(|m| m.lhs() == "<C-A>i1")
+            .unwrap();
+        assert_eq!(mapping.mode(), Some(MapMode::Visual));
+
+        assert!(!Mapper::new(MapMode::Select)
+            .list_all()
+            .unwrap()
+            .iter()
+            .any(|m| m.lhs() == "<C-A>i1"));
+
+        mapper.unmap_in(MapModes::NORMAL, "<C-A>i1").unwrap();
+
+        let error = mapper
+            .unmap_in(MapModes::NORMAL | MapModes::VISUAL, "<C-A>i1")
+            .unwrap_err();
+        assert_eq!(error.errors().len(), 1);
+        assert_eq!(error.errors()[0].0, MapMode::Normal);
+        assert!(matches!(error.errors()[0].1, Error::NoMatches(_)));
+    }
+}
+
+mod abbreviation {
+    use crate::{
+        abbreviation::{AbbrevMode, Abbreviator},
+        buffer::Buffer,
+    };
+    use nvim_api::keymap::Error;
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_abbreviate() {
+        let abbreviator = Abbreviator::new(AbbrevMode::Insert);
+        abbreviator.noreabbrev("overkillteh", "the").unwrap();
+
+        let list = abbreviator.list().unwrap();
+        let abbreviation = list
+            .iter()
+            .find(|a| a.lhs() == "overkillteh")
+            .expect("abbreviation wasn't listed");
+        assert_eq!(abbreviation.rhs(), "the");
+        assert!(abbreviation.noremap());
+        assert!(!abbreviation.buffer());
+
+        let buffer = Buffer::current();
+        abbreviator
+            .buf_abbreviate(buffer, "overkilladn", "and")
+            .unwrap();
+        assert!(abbreviator
+            .list()
+            .unwrap()
+            .iter()
+            .any(|a| a.lhs() == "overkilladn" && a.buffer()));
+
+        abbreviator.unabbreviate("overkillteh").unwrap();
+        assert!(matches!(
+            abbreviator.unabbreviate("overkillteh"),
+            Err(Error::NoMatches(_))
+        ));
+
+        abbreviator.buf_clear(buffer).unwrap();
+        assert!(abbreviator.list().unwrap().is_empty());
+    }
+}
+
+mod feedkeys {
+    use crate::{
+        feedkeys::{feed_sequence, feedkeys, FeedkeysMode},
+        key_code::{KeyCode, KeySequence},
+        vimscript,
+    };
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_feedkeys() {
+        let mode = FeedkeysMode::NOREMAP | FeedkeysMode::EXECUTE;
+
+        feed_sequence(&KeySequence::parse(":let g:overkill_fed = 42<CR>"), mode).unwrap();
+        assert_eq!(vimscript::eval::<i64>("g:overkill_fed").unwrap(), 42);
+
+        feedkeys(&[KeyCode::Esc], mode).unwrap();
+        assert_eq!(
+            nvim_api::nvim::nvim_get_mode()
+                .get("mode")
+                .unwrap()
+                .as_string_unchecked(),
+            "n"
+        );
+
+        feedkeys(&[], mode).unwrap();
+        feed_sequence(&KeySequence::new(), mode).unwrap();
+    }
+}
+
+mod mode {
+    use crate::mode::{CurrentMode, Mode};
+    use nvim_api_test::nvim_test;
+
+    #[nvim_test]
+    fn test_current_mode() {
+        let current_mode = CurrentMode::get().unwrap();
+
+        assert_eq!(current_mode.mode(), Mode::Normal);
+        assert!(current_mode.mode().is_normal_like());
+        assert!(!current_mode.mode().is_insert_like());
+    }
 }
@@ -3,10 +3,15 @@
 
 pub mod map_mode;
 pub mod mapper;
+pub mod registry;
 
-pub use self::{map_mode::MapMode, mapper::Mapper};
+pub use self::{
+    map_mode::{InvalidMapMode, MapMode, MapModes},
+    mapper::{MapModesError, Mapper},
+};
 
-use nvim_api::Dictionary;
+use nvim_api::{keymap::Error, Dictionary};
+use std::{collections::BTreeMap, convert::TryFrom};
 
 /// `Mapper::list_all()` calls an internal call that returns an array of dictionaries, where each
 /// dictionary contains information about a mapping; this struct represents that dictionary.
@@ -14,7 +19,7 @@ use nvim_api::Dictionary;
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub struct Mapping {
-    mode: MapMode,
+    modes: MapModes,
     lhs: String,
     rhs: String,
     sid: i64,
@@ -25,14 +30,23 @@ pub struct Mapping {
     nowait: bool,
     script: bool,
     silent: bool,
+    desc: Option<String>,
 }
 
 impl Mapping {
-    /// The `MapMode` for this mapping.
+    /// The `MapMode` for this mapping, if its modes make up one (ex. not if nvim lists it as
+    /// `nox`, after `:map` then `:sunmap`).
     ///
     #[must_use]
-    pub const fn mode(&self) -> MapMode {
-        self.mode
+    pub fn mode(&self) -> Option<MapMode> {
+        self.modes.as_map_mode()
+    }
+
+    /// The modes that this mapping applies in.
+    ///
+    #[must_use]
+    pub const fn modes(&self) -> MapModes {
+        self.modes
     }
 
     /// The left-hand side of the mapping.
@@ -104,22 +118,278 @@ impl Mapping {
     pub const fn silent(&self) -> bool {
         self.silent
     }
+
+    /// The mapping's description, if it has one.
+    ///
+    #[must_use]
+    pub fn desc(&self) -> Option<&str> {
+        self.desc.as_deref()
+    }
+}
+
+/// The mappings for one lhs, from `Mapper::list_merged()`: the buffer-local one, the global one,
+/// or both, in which case the buffer-local one shadows the global one.
+///
+/// This only pairs up mappings with the same lhs; it doesn't account for a mapping whose lhs is a
+/// prefix of another's.
+///
+#[derive(Debug, Clone)]
+pub struct MergedMapping {
+    lhs: String,
+    buffer_mapping: Option<Mapping>,
+    global_mapping: Option<Mapping>,
 }
 
-impl From<Dictionary> for Mapping {
-    fn from(value: Dictionary) -> Self {
+impl MergedMapping {
+    /// The left-hand side that the mappings are for.
+    ///
+    #[must_use]
+    pub const fn lhs(&self) -> &String {
+        &self.lhs
+    }
+
+    /// The buffer-local mapping, if there is one.
+    ///
+    #[must_use]
+    pub const fn buffer_mapping(&self) -> Option<&Mapping> {
+        self.buffer_mapping.as_ref()
+    }
+
+    /// The global mapping, if there is one.
+    ///
+    #[must_use]
+    pub const fn global_mapping(&self) -> Option<&Mapping> {
+        self.global_mapping.as_ref()
+    }
+
+    /// The mapping that nvim uses: the buffer-local one if there is one, otherwise the global one.
+    ///
+    /// # Panics
+    ///
+    /// This won't panic; a `MergedMapping` always has at least one of the two.
+    ///
+    #[must_use]
+    pub fn effective(&self) -> &Mapping {
+        self.buffer_mapping
+            .as_ref()
+            .or(self.global_mapping.as_ref())
+            .expect("MergedMapping has neither mapping")
+    }
+
+    /// Does the buffer-local mapping shadow a global one?
+    ///
+    #[must_use]
+    pub const fn is_shadowing(&self) -> bool {
+        self.buffer_mapping.is_some() && self.global_mapping.is_some()
+    }
+
+    /// Pairs up `buffer_mappings` and `global_mappings` by lhs. The result is sorted by lhs.
+    ///
+    fn merge(buffer_mappings: Vec<Mapping>, global_mappings: Vec<Mapping>) -> Vec<Self> {
+        let mut merged: BTreeMap<String, Self> = BTreeMap::new();
+
+        for mapping in global_mappings {
+            let lhs = mapping.lhs.clone();
+            merged
+                .entry(lhs)
+                .or_insert_with_key(|lhs| Self::empty(lhs))
+                .global_mapping = Some(mapping);
+        }
+
+        for mapping in buffer_mappings {
+            let lhs = mapping.lhs.clone();
+            merged
+                .entry(lhs)
+                .or_insert_with_key(|lhs| Self::empty(lhs))
+                .buffer_mapping = Some(mapping);
+        }
+
+        merged.into_values().collect()
+    }
+
+    fn empty(lhs: &str) -> Self {
         Self {
-            mode: MapMode::from(value.get_as_string("mode").unwrap()),
-            lhs: value.get_as_string("lhs").unwrap().to_string(),
-            rhs: value.get_as_string("rhs").unwrap().to_string(),
-            lnum: value.get_as_integer("lnum").unwrap(),
-            sid: value.get_as_integer("sid").unwrap(),
-            buffer: value.get_as_boolean("buffer").unwrap(),
-            expr: value.get_as_boolean("expr").unwrap(),
-            noremap: value.get_as_boolean("noremap").unwrap(),
-            nowait: value.get_as_boolean("nowait").unwrap(),
-            script: value.get_as_boolean("script").unwrap(),
-            silent: value.get_as_boolean("silent").unwrap(),
+            lhs: lhs.to_string(),
+            buffer_mapping: None,
+            global_mapping: None,
+        }
+    }
+}
+
+/// Error for when a `Mapping` can't be built from what nvim returned.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MappingError {
+    /// Getting the mappings failed, or a mapping was missing a key.
+    ///
+    #[error(transparent)]
+    Keymap(#[from] Error),
+
+    /// A mapping's `mode` had a char that isn't a known mode.
+    ///
+    #[error(transparent)]
+    InvalidMapMode(#[from] InvalidMapMode),
+}
+
+impl TryFrom<Dictionary> for Mapping {
+    type Error = MappingError;
+
+    fn try_from(value: Dictionary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            modes: MapModes::try_from(get_string(&value, "mode")?.as_str())?,
+            lhs: get_string(&value, "lhs")?,
+            // Mappings with a callback don't have a `rhs`.
+            rhs: get_string(&value, "rhs").unwrap_or_default(),
+            lnum: get_integer(&value, "lnum")?,
+            sid: get_integer(&value, "sid")?,
+            buffer: get_flag(&value, "buffer")?,
+            expr: get_flag(&value, "expr")?,
+            noremap: get_flag(&value, "noremap")?,
+            nowait: get_flag(&value, "nowait")?,
+            script: get_flag(&value, "script")?,
+            silent: get_flag(&value, "silent")?,
+            desc: get_string(&value, "desc").ok(),
+        })
+    }
+}
+
+fn get_string(dict: &Dictionary, key: &'static str) -> Result<String, Error> {
+    dict.get(key)
+        .and_then(|object| object.try_as_string().ok())
+        .map(ToString::to_string)
+        .ok_or(Error::MissingKey {
+            key,
+            expected: "a String",
+        })
+}
+
+fn get_integer(dict: &Dictionary, key: &'static str) -> Result<i64, Error> {
+    dict.get(key)
+        .and_then(|object| object.try_as_integer().ok())
+        .ok_or(Error::MissingKey {
+            key,
+            expected: "an Integer",
+        })
+}
+
+/// nvim returns these flags as `0`/`1` `Integer`s (and `buffer` as the buffer number), but also
+/// accept `Boolean`s.
+///
+fn get_flag(dict: &Dictionary, key: &'static str) -> Result<bool, Error> {
+    dict.get(key)
+        .and_then(|object| {
+            object
+                .try_as_boolean()
+                .ok()
+                .or_else(|| object.try_as_integer().ok().map(|i| i != 0))
+        })
+        .ok_or(Error::MissingKey {
+            key,
+            expected: "a Boolean or an Integer",
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nvim_api::{NvimString, Object};
+
+    #[test]
+    fn test_try_from_dictionary() {
+        let mut dict = Dictionary::default();
+
+        for (key, value) in [("mode", "n"), ("lhs", "<C-A>"), ("rhs", "<Nop>")] {
+            dict.set(
+                NvimString::new_unchecked(key),
+                NvimString::new_unchecked(value),
+            );
+        }
+
+        dict.set(NvimString::new_unchecked("lnum"), Object::from(0));
+        dict.set(NvimString::new_unchecked("sid"), Object::from(-9));
+        dict.set(NvimString::new_unchecked("buffer"), Object::from(3));
+
+        for key in ["expr", "noremap", "nowait", "script"] {
+            dict.set(NvimString::new_unchecked(key), Object::from(0));
+        }
+
+        match Mapping::try_from(dict.clone()) {
+            Err(MappingError::Keymap(Error::MissingKey { key, .. })) => assert_eq!(key, "silent"),
+            other => panic!("Expected a MissingKey error, got {other:?}"),
+        }
+
+        dict.set(NvimString::new_unchecked("silent"), Object::from(true));
+        dict.set(
+            NvimString::new_unchecked("desc"),
+            NvimString::new_unchecked("Does nothing"),
+        );
+
+        let mut bad_mode = dict.clone();
+        bad_mode.set(
+            NvimString::new_unchecked("mode"),
+            NvimString::new_unchecked("nq"),
+        );
+
+        match Mapping::try_from(bad_mode) {
+            Err(MappingError::InvalidMapMode(error)) => {
+                assert_eq!(error.to_string(), "Invalid map mode: 'nq'");
+            }
+            other => panic!("Expected an InvalidMapMode error, got {other:?}"),
+        }
+
+        let mapping = Mapping::try_from(dict).unwrap();
+        assert_eq!(mapping.mode(), Some(MapMode::Normal));
+        assert_eq!(mapping.lhs(), "<C-A>");
+        assert_eq!(mapping.rhs(), "<Nop>");
+        assert_eq!(mapping.sid(), -9);
+        assert!(mapping.buffer());
+        assert!(!mapping.noremap());
+        assert!(mapping.silent());
+        assert_eq!(mapping.desc(), Some("Does nothing"));
+    }
+
+    fn mapping(lhs: &str, rhs: &str, buffer: bool) -> Mapping {
+        Mapping {
+            modes: MapMode::Normal.into(),
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+            sid: 0,
+            lnum: 0,
+            buffer,
+            expr: false,
+            noremap: true,
+            nowait: false,
+            script: false,
+            silent: false,
+            desc: None,
         }
     }
+
+    #[test]
+    fn test_merge() {
+        let merged = MergedMapping::merge(
+            vec![
+                mapping("b", "buffer b", true),
+                mapping("c", "buffer c", true),
+            ],
+            vec![
+                mapping("a", "global a", false),
+                mapping("b", "global b", false),
+            ],
+        );
+
+        let lhss: Vec<&str> = merged.iter().map(|m| m.lhs().as_str()).collect();
+        assert_eq!(lhss, ["a", "b", "c"]);
+
+        assert!(!merged[0].is_shadowing());
+        assert_eq!(merged[0].effective().rhs(), "global a");
+        assert!(merged[0].buffer_mapping().is_none());
+
+        assert!(merged[1].is_shadowing());
+        assert_eq!(merged[1].effective().rhs(), "buffer b");
+        assert_eq!(merged[1].global_mapping().unwrap().rhs(), "global b");
+
+        assert!(!merged[2].is_shadowing());
+        assert_eq!(merged[2].effective().rhs(), "buffer c");
+    }
 }
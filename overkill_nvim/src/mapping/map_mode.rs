@@ -2,6 +2,7 @@
 //! permutations.
 //!
 use nvim_api::{sys::vim, NvimString};
+use std::{convert::TryFrom, ops::BitOr};
 
 /// Represents any possible neovim "mode".
 ///
@@ -79,31 +80,224 @@ impl Default for MapMode {
     }
 }
 
-impl From<&str> for MapMode {
-    fn from(mode: &str) -> Self {
+impl TryFrom<&str> for MapMode {
+    type Error = InvalidMapMode;
+
+    fn try_from(mode: &str) -> Result<Self, Self::Error> {
         match mode {
-            "" | " " => Self::NormalVisualSelectOperatorPending,
-            "n" => Self::Normal,
-            "v" => Self::VisualSelect,
-            "x" => Self::Visual,
-            "s" => Self::Select,
-            "o" => Self::OperatorPending,
-            "i" => Self::Insert,
-            "!" => Self::InsertAndCommandLine,
-            "l" => Self::LanguageMapping,
-            "c" => Self::CommandLine,
-            "t" => Self::TerminalJob,
-            m => {
-                eprintln!("unknown mode '{}', falling back to Mode::Normal", m);
-                Self::Normal
+            "" | " " => Ok(Self::NormalVisualSelectOperatorPending),
+            "n" => Ok(Self::Normal),
+            "v" => Ok(Self::VisualSelect),
+            "x" => Ok(Self::Visual),
+            "s" => Ok(Self::Select),
+            "o" => Ok(Self::OperatorPending),
+            "i" => Ok(Self::Insert),
+            "!" => Ok(Self::InsertAndCommandLine),
+            "l" => Ok(Self::LanguageMapping),
+            "c" => Ok(Self::CommandLine),
+            "t" => Ok(Self::TerminalJob),
+            m => Err(InvalidMapMode(m.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&NvimString> for MapMode {
+    type Error = InvalidMapMode;
+
+    fn try_from(mode: &NvimString) -> Result<Self, Self::Error> {
+        Self::try_from(mode.as_c_str().to_string_lossy().as_ref())
+    }
+}
+
+/// Error for when a string isn't one of the modes that `MapMode` (or `MapModes`) knows.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Invalid map mode: '{}'", _0)]
+pub struct InvalidMapMode(String);
+
+/// A set of the modes that a mapping applies in, for when a mapping needs to be defined for modes
+/// that don't make up a single `MapMode` (ex. normal, visual, and operator-pending, but not
+/// select).
+///
+/// ```
+/// use overkill_nvim::mapping::{MapMode, MapModes};
+///
+/// let modes = MapModes::NORMAL | MapModes::VISUAL | MapModes::OPERATOR_PENDING;
+/// assert_eq!(modes.map_modes(), [MapMode::Normal, MapMode::Visual, MapMode::OperatorPending]);
+/// ```
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapModes(u8);
+
+impl MapModes {
+    /// No modes.
+    ///
+    pub const NONE: Self = Self(0);
+
+    /// Normal mode; `n`.
+    ///
+    pub const NORMAL: Self = Self(1);
+
+    /// Visual mode; `x`.
+    ///
+    pub const VISUAL: Self = Self(1 << 1);
+
+    /// Select mode; `s`.
+    ///
+    pub const SELECT: Self = Self(1 << 2);
+
+    /// Operator-pending mode; `o`.
+    ///
+    pub const OPERATOR_PENDING: Self = Self(1 << 3);
+
+    /// Insert mode; `i`.
+    ///
+    pub const INSERT: Self = Self(1 << 4);
+
+    /// Command-line mode; `c`.
+    ///
+    pub const COMMAND_LINE: Self = Self(1 << 5);
+
+    /// Lang-arg mode (and insert and command-line, for `:lmap`); `l`.
+    ///
+    pub const LANGUAGE_MAPPING: Self = Self(1 << 6);
+
+    /// Terminal-job mode; `t`.
+    ///
+    pub const TERMINAL_JOB: Self = Self(1 << 7);
+
+    /// Are all of `other`'s modes in `self`?
+    ///
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The modes that are in `self`, `other`, or both.
+    ///
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// The modes that are in `self` but not in `other`.
+    ///
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Are there no modes?
+    ///
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The `MapMode`s that make up the set, using as few as possible (ex. normal, visual, select,
+    /// and operator-pending are just `MapMode::NormalVisualSelectOperatorPending`). Each is one
+    /// call to nvim when mapping.
+    ///
+    #[must_use]
+    pub fn map_modes(self) -> Vec<MapMode> {
+        let mut map_modes = Vec::new();
+
+        let nvso = Self::NORMAL | Self::VISUAL | Self::SELECT | Self::OPERATOR_PENDING;
+
+        if self.contains(nvso) {
+            map_modes.push(MapMode::NormalVisualSelectOperatorPending);
+        } else {
+            if self.contains(Self::NORMAL) {
+                map_modes.push(MapMode::Normal);
+            }
+
+            if self.contains(Self::VISUAL | Self::SELECT) {
+                map_modes.push(MapMode::VisualSelect);
+            } else if self.contains(Self::VISUAL) {
+                map_modes.push(MapMode::Visual);
+            } else if self.contains(Self::SELECT) {
+                map_modes.push(MapMode::Select);
+            }
+
+            if self.contains(Self::OPERATOR_PENDING) {
+                map_modes.push(MapMode::OperatorPending);
+            }
+        }
+
+        if self.contains(Self::INSERT | Self::COMMAND_LINE) {
+            map_modes.push(MapMode::InsertAndCommandLine);
+        } else if self.contains(Self::INSERT) {
+            map_modes.push(MapMode::Insert);
+        } else if self.contains(Self::COMMAND_LINE) {
+            map_modes.push(MapMode::CommandLine);
+        }
+
+        if self.contains(Self::LANGUAGE_MAPPING) {
+            map_modes.push(MapMode::LanguageMapping);
+        }
+
+        if self.contains(Self::TERMINAL_JOB) {
+            map_modes.push(MapMode::TerminalJob);
+        }
+
+        map_modes
+    }
+
+    /// The `MapMode` that's exactly these modes, if there is one.
+    ///
+    #[must_use]
+    pub fn as_map_mode(self) -> Option<MapMode> {
+        match self.map_modes().as_slice() {
+            [map_mode] => Some(*map_mode),
+            _ => None,
+        }
+    }
+}
+
+impl BitOr for MapModes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl From<MapMode> for MapModes {
+    fn from(mode: MapMode) -> Self {
+        match mode {
+            MapMode::NormalVisualSelectOperatorPending => {
+                Self::NORMAL | Self::VISUAL | Self::SELECT | Self::OPERATOR_PENDING
             }
+            MapMode::Normal => Self::NORMAL,
+            MapMode::VisualSelect => Self::VISUAL | Self::SELECT,
+            MapMode::Select => Self::SELECT,
+            MapMode::Visual => Self::VISUAL,
+            MapMode::OperatorPending => Self::OPERATOR_PENDING,
+            MapMode::InsertAndCommandLine => Self::INSERT | Self::COMMAND_LINE,
+            MapMode::Insert => Self::INSERT,
+            MapMode::LanguageMapping => Self::LANGUAGE_MAPPING,
+            MapMode::CommandLine => Self::COMMAND_LINE,
+            MapMode::TerminalJob => Self::TERMINAL_JOB,
         }
     }
 }
 
-impl From<&NvimString> for MapMode {
-    fn from(mode: &NvimString) -> Self {
-        mode.as_c_str().to_string_lossy().as_ref().into()
+/// Parses the modes as nvim lists them for a mapping (ex. `nox`), where each char is a `MapMode`.
+///
+impl TryFrom<&str> for MapModes {
+    type Error = InvalidMapMode;
+
+    fn try_from(modes: &str) -> Result<Self, Self::Error> {
+        if modes.trim().is_empty() {
+            return Ok(MapMode::NormalVisualSelectOperatorPending.into());
+        }
+
+        modes.chars().try_fold(Self::NONE, |set, c| {
+            let mode = MapMode::try_from(&*c.encode_utf8(&mut [0; 4]))
+                .map_err(|_| InvalidMapMode(modes.to_string()))?;
+
+            Ok(set | mode.into())
+        })
     }
 }
 
@@ -153,20 +347,66 @@ mod tests {
 
     #[test]
     fn test_from_str() {
-        assert_eq!(MapMode::from("n"), MapMode::Normal);
-        assert_eq!(MapMode::from("v"), MapMode::VisualSelect);
-        assert_eq!(MapMode::from("x"), MapMode::Visual);
-        assert_eq!(MapMode::from("s"), MapMode::Select);
-        assert_eq!(MapMode::from("o"), MapMode::OperatorPending);
-        assert_eq!(MapMode::from("i"), MapMode::Insert);
-        assert_eq!(MapMode::from("c"), MapMode::CommandLine);
-        assert_eq!(MapMode::from("t"), MapMode::TerminalJob);
-        assert_eq!(MapMode::from("!"), MapMode::InsertAndCommandLine);
-        assert_eq!(MapMode::from("l"), MapMode::LanguageMapping);
+        assert_eq!(MapMode::try_from("n").unwrap(), MapMode::Normal);
+        assert_eq!(MapMode::try_from("v").unwrap(), MapMode::VisualSelect);
+        assert_eq!(MapMode::try_from("x").unwrap(), MapMode::Visual);
+        assert_eq!(MapMode::try_from("s").unwrap(), MapMode::Select);
+        assert_eq!(MapMode::try_from("o").unwrap(), MapMode::OperatorPending);
+        assert_eq!(MapMode::try_from("i").unwrap(), MapMode::Insert);
+        assert_eq!(MapMode::try_from("c").unwrap(), MapMode::CommandLine);
+        assert_eq!(MapMode::try_from("t").unwrap(), MapMode::TerminalJob);
+        assert_eq!(
+            MapMode::try_from("!").unwrap(),
+            MapMode::InsertAndCommandLine
+        );
+        assert_eq!(MapMode::try_from("l").unwrap(), MapMode::LanguageMapping);
         assert_eq!(
-            MapMode::from(""),
+            MapMode::try_from("").unwrap(),
             MapMode::NormalVisualSelectOperatorPending
         );
-        assert_eq!(MapMode::from("anything else"), MapMode::Normal);
+        assert!(MapMode::try_from("anything else").is_err());
+        assert!(MapMode::try_from("nox").is_err());
+    }
+
+    #[test]
+    fn test_map_modes() {
+        for mode in [
+            MapMode::NormalVisualSelectOperatorPending,
+            MapMode::Normal,
+            MapMode::VisualSelect,
+            MapMode::Select,
+            MapMode::Visual,
+            MapMode::OperatorPending,
+            MapMode::InsertAndCommandLine,
+            MapMode::Insert,
+            MapMode::LanguageMapping,
+            MapMode::CommandLine,
+            MapMode::TerminalJob,
+        ] {
+            assert_eq!(MapModes::from(mode).as_map_mode(), Some(mode));
+        }
+
+        let modes = MapModes::try_from("nox").unwrap();
+        assert_eq!(
+            modes,
+            MapModes::NORMAL | MapModes::OPERATOR_PENDING | MapModes::VISUAL
+        );
+        assert_eq!(
+            modes.map_modes(),
+            [MapMode::Normal, MapMode::Visual, MapMode::OperatorPending]
+        );
+        assert_eq!(modes.as_map_mode(), None);
+
+        assert_eq!(
+            (MapModes::try_from("v").unwrap() | MapModes::INSERT | MapModes::TERMINAL_JOB)
+                .map_modes(),
+            [MapMode::VisualSelect, MapMode::Insert, MapMode::TerminalJob]
+        );
+        assert_eq!(
+            MapModes::try_from(" ").unwrap().as_map_mode(),
+            Some(MapMode::NormalVisualSelectOperatorPending)
+        );
+        assert!(MapModes::try_from("nq").is_err());
+        assert!(MapModes::NONE.map_modes().is_empty());
     }
 }
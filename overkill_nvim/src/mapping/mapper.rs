@@ -1,32 +1,28 @@
 //! Overkill API for getting and setting mappings.
 //!
-use super::{MapMode, Mapping};
+use super::{registry, MapMode, MapModes, Mapping, MappingError, MergedMapping};
+use crate::{buffer::Buffer, key_code::IntoNotation};
 use nvim_api::{
-    keymap::{get_maps, set_buf_map, set_buf_noremap, set_map, set_noremap, SpecialArguments},
-    Buffer,
+    callback::Callback,
+    keymap::{
+        clear_buf_maps, clear_maps, del_buf_map, del_map, get_buf_maps, get_maps, set_buf_map,
+        set_buf_map_fn, set_buf_noremap, set_keymap, set_map, set_map_fn, set_noremap, Error,
+        SpecialArguments,
+    },
+    Object,
 };
-
-macro_rules! def_bool_return_self_meth {
-    ($field:ident) => {
-        /// Enable this option.
-        ///
-        #[must_use]
-        pub const fn $field(self) -> Self {
-            let arguments = self.arguments.$field();
-
-            let mut s = self;
-            s.arguments = arguments;
-            s
-        }
-    };
-}
+use std::{convert::TryFrom, fmt, rc::Rc};
 
 /// This type provides the main API for getting and setting mappings.
 ///
-#[derive(Debug, Clone, Copy)]
+/// The mappings that a `Mapper` defines (and deletes) are recorded in the `registry`, with their
+/// descriptions; buffer-local ones are recorded for their buffer.
+///
+#[derive(Debug, Clone)]
 pub struct Mapper {
     mode: MapMode,
     arguments: SpecialArguments,
+    desc: Option<Rc<str>>,
 }
 
 impl Mapper {
@@ -37,81 +33,401 @@ impl Mapper {
         Self {
             mode,
             arguments: SpecialArguments::default(),
+            desc: None,
         }
     }
 
-    /// # Panics
+    /// Gives the mappings that get defined with the returned `Mapper` a description, which shows
+    /// up in `:map` listings (and `nvim_get_keymap()`'s `desc`), and in the `registry`.
+    ///
+    /// ```no_run
+    /// use overkill_nvim::mapping::{MapMode, Mapper};
     ///
-    /// This panics if `lhs` or `rhs` can't be converted to internal C-Strings (so don't pass any
-    /// `\0` chars!).
+    /// let mapper = Mapper::new(MapMode::Normal);
+    ///
+    /// for n in 1..=9 {
+    ///     mapper
+    ///         .clone()
+    ///         .desc(&format!("Go to tab {n}"))
+    ///         .noremap(format!("<leader>{n}").as_str(), format!("{n}gt").as_str())
+    ///         .unwrap();
+    /// }
+    /// ```
+    ///
+    #[must_use]
+    pub fn desc(self, desc: &str) -> Self {
+        let mut s = self;
+        s.desc = Some(Rc::from(desc));
+        s
+    }
+
+    /// Names the group of mappings that start with `prefix` (ex. `<leader>g` is "git"), for the
+    /// `Mapper`'s `mode`, in the `registry`.
     ///
-    pub fn map(&self, lhs: &str, rhs: &str) {
-        if self.arguments.any_set() {
-            set_map(self.mode.as_str(), lhs, rhs, Some(self.arguments)).unwrap();
+    pub fn name_group(&self, prefix: &str, name: &str) {
+        registry::set_group(self.mode, prefix, name);
+    }
+
+    /// Maps `lhs` to `rhs`. They can be strings in `<>` notation, or typed keys, like
+    /// `KeySequence`s.
+    ///
+    /// ```no_run
+    /// use overkill_nvim::{
+    ///     key_code::{Key, KeyCode, KeySequence, Modifiers},
+    ///     mapping::{MapMode, Mapper},
+    /// };
+    ///
+    /// let mut lhs = KeySequence::parse("<leader>");
+    /// lhs.push(Key::from(KeyCode::F5).with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT));
+    ///
+    /// Mapper::new(MapMode::Normal).map(&lhs, ":make<CR>").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the mapping (ex. `unique` is set and it already exists).
+    ///
+    pub fn map<L, R>(&self, lhs: L, rhs: R) -> Result<(), Error>
+    where
+        L: IntoNotation,
+        R: IntoNotation,
+    {
+        let (lhs, rhs) = (lhs.into_notation(), rhs.into_notation());
+
+        if self.desc.is_some() {
+            self.set_keymap(None, &lhs, &rhs, false)?;
+        } else if self.arguments.any_set() {
+            set_map(self.mode.as_str(), &lhs, &rhs, Some(self.arguments))?;
         } else {
-            set_map(self.mode.as_str(), lhs, rhs, None).unwrap();
+            set_map(self.mode.as_str(), &lhs, &rhs, None)?;
         }
+
+        self.register(&lhs);
+
+        Ok(())
     }
 
     /// Yields `self` to `f`, but captures `self`'s arguments before, then sets them back after `f`
-    /// is called. It's helpful for reusing a `Mapper` for different purposes.
+    /// is called. It's helpful for reusing a `Mapper` for different purposes. Returns what `f`
+    /// returns.
     ///
     /// ```no_run
     /// use overkill_nvim::mapping::{MapMode, Mapper};
     ///
+    /// # fn main() -> Result<(), nvim_api::keymap::Error> {
     /// let mut normal_mapper = Mapper::new(MapMode::Normal);
     ///
     /// // Use this group for normal+silent mappings.
     /// normal_mapper.group(|mapper| {
     ///     let mapper = mapper.silent();
-    ///     mapper.map("<F2>", "ihello<ESC>");
-    ///     mapper.map("<F4>", "cchello<ESC>");
-    /// });
+    ///     mapper.map("<F2>", "ihello<ESC>")?;
+    ///     mapper.map("<F4>", "cchello<ESC>")
+    /// })?;
     ///
     /// // Use this group for normal+unique mappings.
     /// normal_mapper.group(|mapper| {
     ///     let mapper = mapper.unique();
-    ///     mapper.noremap("<F3>", ":echo 'hi'<CR>");
-    /// });
-    ///
+    ///     mapper.noremap("<F3>", ":echo 'hi'<CR>")
+    /// })?;
+    /// # Ok(())
+    /// # }
     /// ```
     ///
-    pub fn group<F>(&mut self, f: F)
+    pub fn group<F, R>(&mut self, f: F) -> R
     where
-        F: Fn(Self),
+        F: Fn(Self) -> R,
     {
         let args_before = self.arguments;
+        let desc_before = self.desc.clone();
 
-        f(*self);
+        let output = f(self.clone());
 
         self.arguments = args_before;
+        self.desc = desc_before;
+
+        output
     }
 
-    /// # Panics
+    /// Like `map()`, but `rhs` isn't remapped.
+    ///
+    /// # Errors
     ///
-    /// This panics if `lhs` or `rhs` can't be converted to internal C-Strings (so don't pass any
-    /// `\0` chars!)
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the mapping (ex. `unique` is set and it already exists).
     ///
-    pub fn noremap(&self, lhs: &str, rhs: &str) {
-        set_noremap(self.mode.as_str(), lhs, rhs, Some(self.arguments)).unwrap();
+    pub fn noremap<L, R>(&self, lhs: L, rhs: R) -> Result<(), Error>
+    where
+        L: IntoNotation,
+        R: IntoNotation,
+    {
+        let (lhs, rhs) = (lhs.into_notation(), rhs.into_notation());
+
+        if self.desc.is_some() {
+            self.set_keymap(None, &lhs, &rhs, true)?;
+        } else {
+            set_noremap(self.mode.as_str(), &lhs, &rhs, Some(self.arguments))?;
+        }
+
+        self.register(&lhs);
+
+        Ok(())
     }
 
-    /// # Panics
+    /// Like `map()`, but the mapping is local to `buffer`.
+    ///
+    /// # Errors
     ///
-    /// This panics if `lhs` or `rhs` can't be converted to internal C-Strings (so don't pass any
-    /// `\0` chars!)
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the mapping (ex. `buffer` doesn't exist).
     ///
-    pub fn buf_map(&self, buffer: Buffer, lhs: &str, rhs: &str) {
-        set_buf_map(buffer, self.mode.as_str(), lhs, rhs, Some(self.arguments)).unwrap();
+    pub fn buf_map<L, R>(&self, buffer: Buffer, lhs: L, rhs: R) -> Result<(), Error>
+    where
+        L: IntoNotation,
+        R: IntoNotation,
+    {
+        let (lhs, rhs) = (lhs.into_notation(), rhs.into_notation());
+
+        if self.desc.is_some() {
+            self.set_keymap(Some(buffer), &lhs, &rhs, false)?;
+        } else {
+            set_buf_map(
+                buffer.handle(),
+                self.mode.as_str(),
+                &lhs,
+                &rhs,
+                Some(self.arguments),
+            )?;
+        }
+
+        registry::buf_register(buffer, self.mode, &lhs, self.desc.as_deref());
+
+        Ok(())
     }
 
-    /// # Panics
+    /// Like `noremap()`, but the mapping is local to `buffer`.
     ///
-    /// This panics if `lhs` or `rhs` can't be converted to internal C-Strings (so don't pass any
-    /// `\0` chars!)
+    /// # Errors
     ///
-    pub fn buf_noremap(&self, buffer: Buffer, lhs: &str, rhs: &str) {
-        set_buf_noremap(buffer, self.mode.as_str(), lhs, rhs, Some(self.arguments)).unwrap();
+    /// * If `lhs` or `rhs` contain a nul byte.
+    /// * If nvim fails to set the mapping (ex. `buffer` doesn't exist).
+    ///
+    pub fn buf_noremap<L, R>(&self, buffer: Buffer, lhs: L, rhs: R) -> Result<(), Error>
+    where
+        L: IntoNotation,
+        R: IntoNotation,
+    {
+        let (lhs, rhs) = (lhs.into_notation(), rhs.into_notation());
+
+        if self.desc.is_some() {
+            self.set_keymap(Some(buffer), &lhs, &rhs, true)?;
+        } else {
+            set_buf_noremap(
+                buffer.handle(),
+                self.mode.as_str(),
+                &lhs,
+                &rhs,
+                Some(self.arguments),
+            )?;
+        }
+
+        registry::buf_register(buffer, self.mode, &lhs, self.desc.as_deref());
+
+        Ok(())
+    }
+
+    /// Maps `lhs` to call `f`, instead of to a `rhs`.
+    ///
+    /// ```no_run
+    /// use overkill_nvim::mapping::{MapMode, Mapper};
+    ///
+    /// let mut count = 0;
+    ///
+    /// Mapper::new(MapMode::Normal)
+    ///     .silent()
+    ///     .map_fn("<F5>", move || {
+    ///         count += 1;
+    ///         println!("You've pressed <F5> {count} times");
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` contains a nul byte.
+    /// * If the callback can't be created, or nvim fails to set the mapping.
+    ///
+    pub fn map_fn<L, F>(&self, lhs: L, f: F) -> Result<(), Error>
+    where
+        L: IntoNotation,
+        F: FnMut() + 'static,
+    {
+        let lhs = lhs.into_notation();
+
+        set_map_fn(
+            self.mode.as_str(),
+            &lhs,
+            callback_from(f)?,
+            Some(self.arguments),
+            self.desc.as_deref(),
+        )?;
+
+        self.register(&lhs);
+
+        Ok(())
+    }
+
+    /// Like `map_fn()`, but the mapping is local to `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// * If `lhs` contains a nul byte.
+    /// * If the callback can't be created, or nvim fails to set the mapping.
+    ///
+    pub fn buf_map_fn<L, F>(&self, buffer: Buffer, lhs: L, f: F) -> Result<(), Error>
+    where
+        L: IntoNotation,
+        F: FnMut() + 'static,
+    {
+        let lhs = lhs.into_notation();
+
+        set_buf_map_fn(
+            buffer.handle(),
+            self.mode.as_str(),
+            &lhs,
+            callback_from(f)?,
+            Some(self.arguments),
+            self.desc.as_deref(),
+        )?;
+
+        registry::buf_register(buffer, self.mode, &lhs, self.desc.as_deref());
+
+        Ok(())
+    }
+
+    /// Deletes the mapping for `lhs`. If `buffer` is set, this deletes the current buffer's
+    /// mapping for `lhs`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::NoMatches` if there's no mapping for `lhs`.
+    /// * If `lhs` contains a nul byte.
+    ///
+    pub fn unmap<L: IntoNotation>(&self, lhs: L) -> Result<(), Error> {
+        let lhs = lhs.into_notation();
+
+        del_map(self.mode.as_str(), &lhs, Some(self.arguments))?;
+
+        if self.arguments.is_buffer() {
+            registry::buf_unregister(Buffer::current(), self.mode, &lhs);
+        } else {
+            registry::unregister(self.mode, &lhs);
+        }
+
+        Ok(())
+    }
+
+    /// Like `map()`, but for each of `modes`, instead of the `Mapper`'s `mode`. It's one mapping
+    /// per `MapMode` that `modes` is made up of; if some fail, the rest are still defined.
+    ///
+    /// ```no_run
+    /// use overkill_nvim::mapping::{MapMode, MapModes, Mapper};
+    ///
+    /// // Like `:map`, but not in select mode.
+    /// let modes = MapModes::NORMAL | MapModes::VISUAL | MapModes::OPERATOR_PENDING;
+    ///
+    /// Mapper::new(MapMode::Normal).map_in(modes, "<leader>y", "\"+y").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * The errors from the modes where `map()` failed.
+    ///
+    pub fn map_in<L, R>(&self, modes: MapModes, lhs: L, rhs: R) -> Result<(), MapModesError>
+    where
+        L: IntoNotation,
+        R: IntoNotation,
+    {
+        let (lhs, rhs) = (lhs.into_notation(), rhs.into_notation());
+
+        self.each_mode(modes, |mapper| mapper.map(lhs.as_str(), rhs.as_str()))
+    }
+
+    /// Like `noremap()`, but for each of `modes`, instead of the `Mapper`'s `mode` (see
+    /// `map_in()`).
+    ///
+    /// # Errors
+    ///
+    /// * The errors from the modes where `noremap()` failed.
+    ///
+    pub fn noremap_in<L, R>(&self, modes: MapModes, lhs: L, rhs: R) -> Result<(), MapModesError>
+    where
+        L: IntoNotation,
+        R: IntoNotation,
+    {
+        let (lhs, rhs) = (lhs.into_notation(), rhs.into_notation());
+
+        self.each_mode(modes, |mapper| mapper.noremap(lhs.as_str(), rhs.as_str()))
+    }
+
+    /// Like `unmap()`, but for each of `modes`, instead of the `Mapper`'s `mode` (see
+    /// `map_in()`).
+    ///
+    /// # Errors
+    ///
+    /// * The errors from the modes where `unmap()` failed (ex. `Error::NoMatches`).
+    ///
+    pub fn unmap_in<L: IntoNotation>(&self, modes: MapModes, lhs: L) -> Result<(), MapModesError> {
+        let lhs = lhs.into_notation();
+
+        self.each_mode(modes, |mapper| mapper.unmap(lhs.as_str()))
+    }
+
+    /// Deletes `buffer`'s mapping for `lhs`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::NoMatches` if `buffer` has no mapping for `lhs`.
+    /// * If `lhs` contains a nul byte, or nvim fails to delete the mapping.
+    ///
+    pub fn buf_unmap<L: IntoNotation>(&self, buffer: Buffer, lhs: L) -> Result<(), Error> {
+        let lhs = lhs.into_notation();
+
+        del_buf_map(buffer.handle(), self.mode.as_str(), &lhs)?;
+        registry::buf_unregister(buffer, self.mode, &lhs);
+
+        Ok(())
+    }
+
+    /// Deletes all mappings for the `Mapper`'s `mode`, like `:mapclear`. If `buffer` is set, this
+    /// deletes the current buffer's mappings instead (like `:mapclear <buffer>`).
+    ///
+    /// # Errors
+    ///
+    /// * If nvim fails to get the current buffer.
+    ///
+    pub fn clear(&self) -> Result<(), Error> {
+        if self.arguments.is_buffer() {
+            self.buf_clear(Buffer::current())
+        } else {
+            clear_maps(self.mode.as_str())?;
+            registry::clear(self.mode);
+
+            Ok(())
+        }
+    }
+
+    /// Deletes all of `buffer`'s mappings for the `Mapper`'s `mode`, like `:mapclear <buffer>`.
+    ///
+    /// # Errors
+    ///
+    /// * If `buffer` doesn't exist.
+    ///
+    pub fn buf_clear(&self, buffer: Buffer) -> Result<(), Error> {
+        clear_buf_maps(buffer.handle(), self.mode.as_str())?;
+        registry::buf_clear(buffer, self.mode);
+
+        Ok(())
     }
 
     def_bool_return_self_meth!(buffer);
@@ -123,40 +439,178 @@ impl Mapper {
 
     /// Gets a list of all mappings for the `Mapper`'s `mode`.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This will panic if the call to `nvim_api::keymaps::get_maps()` fails.
+    /// * If the call to `nvim_api::keymap::get_maps()` fails.
+    /// * If nvim returns a mapping that a `Mapping` can't be built from.
     ///
-    #[must_use]
-    pub fn list_all(&self) -> Vec<Mapping> {
-        get_maps(self.mode.as_str())
-            .unwrap_or_else(|_| panic!("Unable to retrive maps for mode '{}'", self.mode.as_str()))
+    pub fn list_all(&self) -> Result<Vec<Mapping>, MappingError> {
+        get_maps(self.mode.as_str())?
+            .into_iter()
+            .map(Mapping::try_from)
+            .collect()
+    }
+
+    /// Gets a list of `buffer`'s buffer-local mappings for the `Mapper`'s `mode`.
+    ///
+    /// # Errors
+    ///
+    /// * If the call to `nvim_api::keymap::get_buf_maps()` fails (ex. `buffer` doesn't exist).
+    /// * If nvim returns a mapping that a `Mapping` can't be built from.
+    ///
+    pub fn list_for_buffer(&self, buffer: Buffer) -> Result<Vec<Mapping>, MappingError> {
+        get_buf_maps(buffer.handle(), self.mode.as_str())?
             .into_iter()
-            .map(Mapping::from)
+            .map(Mapping::try_from)
             .collect()
     }
 
+    /// Gets all of the mappings for the `Mapper`'s `mode` that apply in `buffer`, global and
+    /// buffer-local, paired up by lhs; that shows which buffer-local mappings shadow global ones.
+    ///
+    /// # Errors
+    ///
+    /// * If getting the global or the buffer-local mappings fails.
+    ///
+    pub fn list_merged(&self, buffer: Buffer) -> Result<Vec<MergedMapping>, MappingError> {
+        Ok(MergedMapping::merge(
+            self.list_for_buffer(buffer)?,
+            self.list_all()?,
+        ))
+    }
+
     /// Gets a list of mappings that match `Mapper`'s `mode` and `arguments`.
-    /// Note that this doesn't check for `unique` or `noremap`.
+    /// Note that this doesn't check for `unique` or `noremap`. If `buffer` is set, these are the
+    /// current buffer's mappings.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This will panic if the call to `nvim_api::keymaps::get_maps()` fails.
+    /// * If the call to `nvim_api::keymap::get_maps()` (or `get_buf_maps()`) fails.
+    /// * If nvim returns a mapping that a `Mapping` can't be built from.
     ///
-    #[must_use]
-    pub fn list(&self) -> Vec<Mapping> {
-        get_maps(self.mode.as_str())
-            .unwrap_or_else(|_| panic!("Unable to retrive maps for mode '{}'", self.mode.as_str()))
+    pub fn list(&self) -> Result<Vec<Mapping>, MappingError> {
+        let mut mappings = if self.arguments.is_buffer() {
+            self.list_for_buffer(Buffer::current())?
+        } else {
+            self.list_all()?
+        };
+
+        mappings.retain(|mapping| {
+            mapping.modes() == MapModes::from(self.mode)
+                && mapping.buffer() == self.arguments.is_buffer()
+                && mapping.expr() == self.arguments.is_expr()
+                && mapping.nowait() == self.arguments.is_nowait()
+                && mapping.script() == self.arguments.is_script()
+                && mapping.silent() == self.arguments.is_silent()
+        });
+
+        Ok(mappings)
+    }
+
+    /// Sets the mapping with `nvim_api::keymap::set_keymap()`, which (unlike `set_map()`) takes a
+    /// `desc`.
+    ///
+    fn set_keymap(
+        &self,
+        buffer: Option<Buffer>,
+        lhs: &str,
+        rhs: &str,
+        noremap: bool,
+    ) -> Result<(), Error> {
+        set_keymap(
+            buffer.map(Buffer::handle),
+            self.mode.as_str(),
+            lhs,
+            rhs,
+            noremap,
+            Some(self.arguments),
+            self.desc.as_deref(),
+        )
+    }
+
+    /// Calls `f` with a copy of `self` for each `MapMode` in `modes`, collecting the errors.
+    ///
+    fn each_mode<F>(&self, modes: MapModes, f: F) -> Result<(), MapModesError>
+    where
+        F: Fn(Self) -> Result<(), Error>,
+    {
+        let errors: Vec<(MapMode, Error)> = modes
+            .map_modes()
             .into_iter()
-            .map(Mapping::from)
-            .filter(|mapping| {
-                mapping.mode() == self.mode
-                    && mapping.buffer() == self.arguments.is_buffer()
-                    && mapping.expr() == self.arguments.is_expr()
-                    && mapping.nowait() == self.arguments.is_nowait()
-                    && mapping.script() == self.arguments.is_script()
-                    && mapping.silent() == self.arguments.is_silent()
+            .filter_map(|mode| {
+                let mapper = Self {
+                    mode,
+                    ..self.clone()
+                };
+
+                f(mapper).err().map(|error| (mode, error))
             })
-            .collect()
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(MapModesError { errors })
+        }
+    }
+
+    /// Records a mapping in the `registry`; if `buffer` is set, it's recorded for the current
+    /// buffer.
+    ///
+    fn register(&self, lhs: &str) {
+        let desc = self.desc.as_deref();
+
+        if self.arguments.is_buffer() {
+            registry::buf_register(Buffer::current(), self.mode, lhs, desc);
+        } else {
+            registry::register(self.mode, lhs, desc);
+        }
+    }
+}
+
+/// Error from a `Mapper` method that works on `MapModes` (ex. `map_in()`), with the error for each
+/// mode that it failed in.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+pub struct MapModesError {
+    errors: Vec<(MapMode, Error)>,
+}
+
+impl MapModesError {
+    /// The modes that failed, with why.
+    ///
+    #[must_use]
+    pub fn errors(&self) -> &[(MapMode, Error)] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for MapModesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Failed in ")?;
+
+        for (i, (mode, error)) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "mode '{}': {error}", mode.as_str())?;
+        }
+
+        Ok(())
     }
 }
+
+/// Wraps `f` in a `Callback` for a mapping.
+///
+fn callback_from<F>(mut f: F) -> Result<Callback, Error>
+where
+    F: FnMut() + 'static,
+{
+    let callback = Callback::new(move |_| {
+        f();
+        Ok(Object::new_nil())
+    })?;
+
+    Ok(callback)
+}
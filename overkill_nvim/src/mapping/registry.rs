@@ -0,0 +1,537 @@
+//! This module keeps a registry of the mappings that get defined through `Mapper` (global and
+//! buffer-local), along with their descriptions and names for groups of them (ex. `<leader>g` is
+//! "git").
+//!
+//! nvim doesn't know about groups, and getting at the tree of what can be typed after a prefix
+//! would mean parsing `:map` output, so this is what a help popup (a la which-key) can be built
+//! from.
+//!
+//! ```no_run
+//! use overkill_nvim::mapping::{registry, MapMode, Mapper};
+//!
+//! let mapper = Mapper::new(MapMode::Normal);
+//! mapper.name_group("<leader>g", "git");
+//! mapper.clone().desc("Status").noremap("<leader>gs", ":Git<CR>").unwrap();
+//! mapper.clone().desc("Blame").noremap("<leader>gb", ":Git blame<CR>").unwrap();
+//!
+//! for node in registry::continuations(MapMode::Normal, "<leader>") {
+//!     // "g": "git" (2 mappings)
+//!     println!("{}: {:?} ({} mappings)", node.key(), node.label(), node.children().len());
+//! }
+//! ```
+//!
+use super::{MapMode, MapModes};
+use crate::{
+    buffer::Buffer,
+    key_code::{Key, KeySequence},
+};
+use std::{cell::RefCell, collections::BTreeMap};
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+/// A registered mapping's description, or a group's name. `modes` shrinks as modes get unmapped,
+/// the same as nvim's (ex. `:nunmap` of a `:map` mapping leaves it in visual, select and
+/// operator-pending modes).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    modes: MapModes,
+    buffer: Option<Buffer>,
+    keys: KeySequence,
+    text: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    mappings: Vec<Entry>,
+    groups: Vec<Entry>,
+}
+
+/// A key that can be typed after a prefix, in the tree that `continuations()` returns.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyNode {
+    key: Key,
+    lhs: KeySequence,
+    desc: Option<String>,
+    group: Option<String>,
+    is_mapping: bool,
+    children: Vec<Self>,
+}
+
+impl KeyNode {
+    /// The key (ex. `g`, or `<C-A>`).
+    ///
+    #[must_use]
+    pub const fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// All of the keys, from the start of the mapping through `key`.
+    ///
+    #[must_use]
+    pub const fn lhs(&self) -> &KeySequence {
+        &self.lhs
+    }
+
+    /// The description of the mapping for `lhs`, if there's one and it has a description.
+    ///
+    #[must_use]
+    pub fn desc(&self) -> Option<&str> {
+        self.desc.as_deref()
+    }
+
+    /// The name of the group for `lhs`, if one was given.
+    ///
+    #[must_use]
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// What to show for the node: the group name, else the mapping's description.
+    ///
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.group().or_else(|| self.desc())
+    }
+
+    /// Is there a mapping for `lhs` (and not just for longer ones that start with it)?
+    ///
+    #[must_use]
+    pub const fn is_mapping(&self) -> bool {
+        self.is_mapping
+    }
+
+    /// Are there mappings that continue from `lhs`?
+    ///
+    #[must_use]
+    pub const fn is_prefix(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// The keys that can be typed after `lhs`, sorted by key.
+    ///
+    #[must_use]
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+}
+
+/// Records that `lhs` is mapped in `mode`, with `desc`. Registering `lhs` again replaces the
+/// entry in the modes that the new one is for. `Mapper` calls this for the global mappings that
+/// it defines.
+///
+pub fn register(mode: MapMode, lhs: &str, desc: Option<&str>) {
+    register_entry(None, mode, lhs, desc);
+}
+
+/// Like `register()`, but for a mapping that's local to `buffer`.
+///
+pub fn buf_register(buffer: Buffer, mode: MapMode, lhs: &str, desc: Option<&str>) {
+    register_entry(Some(buffer), mode, lhs, desc);
+}
+
+/// Removes `mode` from the entry for `lhs`; like with `:unmap`, a mapping that's defined for more
+/// modes stays registered for the rest of them.
+///
+pub fn unregister(mode: MapMode, lhs: &str) {
+    unregister_entry(None, mode, lhs);
+}
+
+/// Like `unregister()`, but for `buffer`'s mapping for `lhs`.
+///
+pub fn buf_unregister(buffer: Buffer, mode: MapMode, lhs: &str) {
+    unregister_entry(Some(buffer), mode, lhs);
+}
+
+/// Removes `mode` from all of the global entries (group names are kept).
+///
+pub fn clear(mode: MapMode) {
+    clear_entries(None, mode);
+}
+
+/// Removes `mode` from all of `buffer`'s entries.
+///
+pub fn buf_clear(buffer: Buffer, mode: MapMode) {
+    clear_entries(Some(buffer), mode);
+}
+
+/// Names the group of mappings that start with `prefix` in `mode` (ex. `<leader>g` is "git").
+///
+pub fn set_group(mode: MapMode, prefix: &str, name: &str) {
+    REGISTRY.with(|registry| {
+        set_entry(
+            &mut registry.borrow_mut().groups,
+            None,
+            mode,
+            prefix,
+            Some(name.to_string()),
+        );
+    });
+}
+
+/// Gets the tree of the registered global mappings that can be typed after `prefix` in `mode`:
+/// each `KeyNode` is a key that can come next, with the keys that can come after it as its
+/// children.
+///
+/// Mappings that are defined for more than one mode apply to each of those modes (ex. a
+/// `MapMode::NormalVisualSelectOperatorPending` mapping is included for `MapMode::Normal`).
+///
+/// Keys are compared as `KeySequence`s, so `<Leader>` and `<leader>` are the same.
+///
+#[must_use]
+pub fn continuations(mode: MapMode, prefix: &str) -> Vec<KeyNode> {
+    REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .continuations(mode, None, &KeySequence::parse(prefix))
+    })
+}
+
+/// Like `continuations()`, but includes `buffer`'s mappings too; they win over global ones for
+/// the same keys, like they do in nvim.
+///
+#[must_use]
+pub fn buf_continuations(buffer: Buffer, mode: MapMode, prefix: &str) -> Vec<KeyNode> {
+    REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .continuations(mode, Some(buffer), &KeySequence::parse(prefix))
+    })
+}
+
+fn register_entry(buffer: Option<Buffer>, mode: MapMode, lhs: &str, desc: Option<&str>) {
+    REGISTRY.with(|registry| {
+        set_entry(
+            &mut registry.borrow_mut().mappings,
+            buffer,
+            mode,
+            lhs,
+            desc.map(ToString::to_string),
+        );
+    });
+}
+
+fn unregister_entry(buffer: Option<Buffer>, mode: MapMode, lhs: &str) {
+    let keys = KeySequence::parse(lhs);
+
+    REGISTRY.with(|registry| {
+        remove_modes(&mut registry.borrow_mut().mappings, mode, |entry| {
+            entry.buffer == buffer && entry.keys == keys
+        });
+    });
+}
+
+fn clear_entries(buffer: Option<Buffer>, mode: MapMode) {
+    REGISTRY.with(|registry| {
+        remove_modes(&mut registry.borrow_mut().mappings, mode, |entry| {
+            entry.buffer == buffer
+        });
+    });
+}
+
+impl Registry {
+    fn continuations(
+        &self,
+        mode: MapMode,
+        buffer: Option<Buffer>,
+        prefix: &KeySequence,
+    ) -> Vec<KeyNode> {
+        let mut root = Builder::default();
+
+        // Buffer-local mappings go last, so they win over global ones.
+        let mut mappings: Vec<&Entry> = applicable(&self.mappings, mode, prefix)
+            .filter(|entry| entry.buffer.is_none() || entry.buffer == buffer)
+            .collect();
+        mappings.sort_by_key(|entry| entry.buffer.is_some());
+
+        for entry in mappings {
+            let node = root.node_at(&entry.keys.keys()[prefix.len()..]);
+            node.is_mapping = true;
+            node.desc.clone_from(&entry.text);
+        }
+
+        for entry in applicable(&self.groups, mode, prefix) {
+            // Only name groups that have mappings.
+            if let Some(node) = root.existing_node_at(&entry.keys.keys()[prefix.len()..]) {
+                node.group.clone_from(&entry.text);
+            }
+        }
+
+        root.build(prefix)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Builder {
+    desc: Option<String>,
+    group: Option<String>,
+    is_mapping: bool,
+    children: BTreeMap<Key, Self>,
+}
+
+impl Builder {
+    fn node_at(&mut self, keys: &[Key]) -> &mut Self {
+        keys.iter().fold(self, |node, key| {
+            node.children.entry(key.clone()).or_default()
+        })
+    }
+
+    fn existing_node_at(&mut self, keys: &[Key]) -> Option<&mut Self> {
+        keys.iter()
+            .try_fold(self, |node, key| node.children.get_mut(key))
+    }
+
+    fn build(self, lhs: &KeySequence) -> Vec<KeyNode> {
+        self.children
+            .into_iter()
+            .map(|(key, builder)| {
+                let mut lhs = lhs.clone();
+                lhs.push(key.clone());
+
+                KeyNode {
+                    desc: builder.desc.clone(),
+                    group: builder.group.clone(),
+                    is_mapping: builder.is_mapping,
+                    children: builder.build(&lhs),
+                    key,
+                    lhs,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Adds an entry for `lhs` in `mode`, taking `mode` away from the existing entries for `lhs`, so
+/// there's one entry per mode.
+///
+fn set_entry(
+    entries: &mut Vec<Entry>,
+    buffer: Option<Buffer>,
+    mode: MapMode,
+    lhs: &str,
+    text: Option<String>,
+) {
+    let keys = KeySequence::parse(lhs);
+
+    remove_modes(entries, mode, |entry| {
+        entry.buffer == buffer && entry.keys == keys
+    });
+
+    entries.push(Entry {
+        modes: MapModes::from(mode),
+        buffer,
+        keys,
+        text,
+    });
+}
+
+/// Takes `mode` away from the entries that `f` matches, dropping the ones that are left with no
+/// modes.
+///
+fn remove_modes<F>(entries: &mut Vec<Entry>, mode: MapMode, f: F)
+where
+    F: Fn(&Entry) -> bool,
+{
+    for entry in entries.iter_mut().filter(|entry| f(entry)) {
+        entry.modes = entry.modes.difference(MapModes::from(mode));
+    }
+
+    entries.retain(|entry| !entry.modes.is_empty());
+}
+
+/// The entries that apply in `mode` and are longer than `prefix`, which they start with.
+///
+fn applicable<'a>(
+    entries: &'a [Entry],
+    mode: MapMode,
+    prefix: &'a KeySequence,
+) -> impl Iterator<Item = &'a Entry> {
+    entries.iter().filter(move |entry| {
+        entry.modes.contains(MapModes::from(mode))
+            && entry.keys.len() > prefix.len()
+            && entry.keys.starts_with(prefix)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(mappings: &[(MapMode, &str, &str)], groups: &[(MapMode, &str, &str)]) -> Registry {
+        let mut registry = Registry::default();
+
+        for &(mode, lhs, text) in mappings {
+            set_entry(
+                &mut registry.mappings,
+                None,
+                mode,
+                lhs,
+                Some(text.to_string()),
+            );
+        }
+
+        for &(mode, lhs, text) in groups {
+            set_entry(
+                &mut registry.groups,
+                None,
+                mode,
+                lhs,
+                Some(text.to_string()),
+            );
+        }
+
+        registry
+    }
+
+    /// The lhs and desc of each key that can be typed after `g`.
+    ///
+    fn descs(
+        registry: &Registry,
+        mode: MapMode,
+        buffer: Option<Buffer>,
+    ) -> Vec<(String, Option<String>)> {
+        registry
+            .continuations(mode, buffer, &KeySequence::parse("g"))
+            .iter()
+            .map(|node| (node.lhs().to_string(), node.desc().map(ToString::to_string)))
+            .collect()
+    }
+
+    #[test]
+    fn test_continuations() {
+        let registry = registry(
+            &[
+                (MapMode::Normal, "<leader>gs", "Status"),
+                (MapMode::Normal, "<leader>gb", "Blame"),
+                (
+                    MapMode::NormalVisualSelectOperatorPending,
+                    "<Leader>f",
+                    "Find",
+                ),
+                (MapMode::Normal, "<leader>f", "Find files"),
+                (MapMode::Insert, "<leader>x", "Insert only"),
+                (MapMode::Normal, "gx", "Not under the prefix"),
+            ],
+            &[
+                (MapMode::Normal, "<leader>g", "git"),
+                (MapMode::Normal, "<leader>z", "no mappings"),
+            ],
+        );
+
+        let tree = registry.continuations(MapMode::Normal, None, &KeySequence::parse("<leader>"));
+        let keys: Vec<String> = tree.iter().map(|node| node.key().to_string()).collect();
+        assert_eq!(keys, ["f", "g"]);
+
+        assert_eq!(tree[0].lhs().to_string(), "<Leader>f");
+        assert_eq!(tree[0].desc(), Some("Find files"));
+        assert!(tree[0].is_mapping());
+        assert!(!tree[0].is_prefix());
+
+        assert_eq!(tree[1].group(), Some("git"));
+        assert_eq!(tree[1].label(), Some("git"));
+        assert!(!tree[1].is_mapping());
+        assert!(tree[1].is_prefix());
+
+        let git: Vec<(String, Option<&str>)> = tree[1]
+            .children()
+            .iter()
+            .map(|node| (node.lhs().to_string(), node.desc()))
+            .collect();
+        assert_eq!(
+            git,
+            [
+                ("<Leader>gb".to_string(), Some("Blame")),
+                ("<Leader>gs".to_string(), Some("Status"))
+            ]
+        );
+
+        let tree = registry.continuations(MapMode::Visual, None, &KeySequence::parse("<leader>"));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].desc(), Some("Find"));
+
+        assert!(registry
+            .continuations(MapMode::Normal, None, &KeySequence::parse("<leader>gs"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_continuations_normalized() {
+        let registry = registry(&[(MapMode::Normal, "<C-B>g1<Return>", "First")], &[]);
+
+        let tree = registry.continuations(MapMode::Normal, None, &KeySequence::parse("<C-b>"));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].children()[0].lhs().to_string(), "<C-B>g1");
+
+        assert_eq!(
+            registry.mappings[0].keys,
+            KeySequence::parse("<c-b>g1<Enter>")
+        );
+    }
+
+    #[test]
+    fn test_remove_modes() {
+        let mut registry = registry(
+            &[(MapMode::NormalVisualSelectOperatorPending, "gx", "Open")],
+            &[],
+        );
+
+        remove_modes(&mut registry.mappings, MapMode::Normal, |_| true);
+        assert!(descs(&registry, MapMode::Normal, None).is_empty());
+        assert_eq!(
+            descs(&registry, MapMode::Visual, None),
+            [("gx".to_string(), Some("Open".to_string()))]
+        );
+        assert_eq!(descs(&registry, MapMode::OperatorPending, None).len(), 1);
+
+        remove_modes(&mut registry.mappings, MapMode::VisualSelect, |_| true);
+        remove_modes(&mut registry.mappings, MapMode::OperatorPending, |_| true);
+        assert!(registry.mappings.is_empty());
+    }
+
+    #[test]
+    fn test_buffer_continuations() {
+        let mut registry = registry(
+            &[
+                (MapMode::Normal, "gx", "Global"),
+                (MapMode::Normal, "gy", "Global only"),
+            ],
+            &[],
+        );
+        let buffer = Buffer::from(nvim_api::Buffer::new(1));
+        let other = Buffer::from(nvim_api::Buffer::new(2));
+        set_entry(
+            &mut registry.mappings,
+            Some(buffer),
+            MapMode::Normal,
+            "gx",
+            Some("Local".to_string()),
+        );
+        set_entry(
+            &mut registry.mappings,
+            Some(buffer),
+            MapMode::Normal,
+            "gz",
+            None,
+        );
+
+        assert_eq!(
+            descs(&registry, MapMode::Normal, None),
+            [
+                ("gx".to_string(), Some("Global".to_string())),
+                ("gy".to_string(), Some("Global only".to_string()))
+            ]
+        );
+        assert_eq!(
+            descs(&registry, MapMode::Normal, Some(buffer)),
+            [
+                ("gx".to_string(), Some("Local".to_string())),
+                ("gy".to_string(), Some("Global only".to_string())),
+                ("gz".to_string(), None)
+            ]
+        );
+        assert_eq!(descs(&registry, MapMode::Normal, Some(other)).len(), 2);
+    }
+}
@@ -1,72 +1,496 @@
-//#[derive(Debug, Clone, Copy)]
-//#[allow(clippy::module_name_repetitions)]
-//pub struct CurrentMode {
-//    mode: Mode,
-//    blocking: bool,
-//}
-
-//impl CurrentMode {
-//    /// The current mode.
-//    ///
-//    pub const fn mode(self) -> Mode {
-//        self.mode
-//    }
-
-//    /// `true` if Nvim is waiting for input.
-//    ///
-//    pub const fn blocking(self) -> bool {
-//        self.blocking
-//    }
-//}
-
-//impl TryFrom<Dictionary> for CurrentMode {
-//    type Error = CurrentModeError;
-
-//    fn try_from(dict: Dictionary) -> Result<Self, Self::Error> {
-//        match (dict.get("mode"), dict.get("blocking")) {
-//            (Some(mode), Some(blocking)) if mode.is_string() && blocking.is_boolean() => Ok(Self {
-//                mode: Mode::from(mode.as_string_unchecked()),
-//                blocking: blocking.as_boolean_unchecked(),
-//            }),
-//            (None, Some(_)) => Err(CurrentModeError::Mode),
-//            (Some(_), None) => Err(CurrentModeError::Blocking),
-//            _ => Err(CurrentModeError::ModeAndBlocking),
-//        }
-//    }
-//}
-
-///// Error type for instantiating a `CurrentMode` from the `Dictionary` returned by neovim on
-///// related calls.
-/////
-//#[derive(Debug, Clone, Copy, thiserror::Error)]
-//pub enum CurrentModeError {
-//    #[error("Underlying neovim dictionary did not have the `mode` key/value pair set")]
-//    Mode,
-
-//    #[error("Underlying neovim dictionary did not have the `blocking` key/value pair set")]
-//    Blocking,
-
-//    #[error(
-//        "Underlying neovim dictionary did not have the `mode` or `blocking` key/value pairs set"
-//    )]
-//    ModeAndBlocking,
-//}
-
-//#[cfg(test)]
-//mod tests {
-//    use super::*;
-//    use neovim_api::sys::nvim::{KeyValuePair, Object};
-
-//    #[test]
-//    fn try_from_dictionary_test() {
-//        let dict = Dictionary::new_from([
-//            KeyValuePair::new(
-//                NvimString::new_unchecked("mode"),
-//                Object::from(NvimString::new_unchecked("n")),
-//            ),
-//            KeyValuePair::new(NvimString::new_unchecked("blocking"), Object::from(false)),
-//        ]);
-//        let current_mode = CurrentMode::try_from(dict).unwrap();
-//        assert_eq!(current_mode.mode(), Mode::Normal);
-//    }
-//}
+//!
+//! This module contains types for the mode that nvim is in (see `:help mode()`), as returned by
+//! `nvim_get_mode()`.
+//!
+//! ```no_run
+//! use overkill_nvim::mode::CurrentMode;
+//!
+//! let current_mode = CurrentMode::get().unwrap();
+//!
+//! if current_mode.mode().is_visual_like() && !current_mode.blocking() {
+//!     println!("Selecting, in {}", current_mode.mode());
+//! }
+//! ```
+//!
+use nvim_api::{nvim, Dictionary};
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+/// Every mode that nvim reports (see `:help mode()`). Variants are named for the mode, then for
+/// the sub-mode (ex. `InsertCompletion` is `ic`).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// `n`: Normal.
+    ///
+    Normal,
+
+    /// `no`: Operator-pending.
+    ///
+    OperatorPending,
+
+    /// `nov`: Operator-pending, forced charwise (see `:help o_v`).
+    ///
+    OperatorPendingCharwise,
+
+    /// `noV`: Operator-pending, forced linewise (see `:help o_V`).
+    ///
+    OperatorPendingLinewise,
+
+    /// `no^V`: Operator-pending, forced blockwise (see `:help o_CTRL-V`).
+    ///
+    OperatorPendingBlockwise,
+
+    /// `niI`: Normal, from `i_CTRL-O` in insert mode.
+    ///
+    NormalFromInsert,
+
+    /// `niR`: Normal, from `i_CTRL-O` in replace mode.
+    ///
+    NormalFromReplace,
+
+    /// `niV`: Normal, from `i_CTRL-O` in virtual-replace mode.
+    ///
+    NormalFromVirtualReplace,
+
+    /// `nt`: Normal, in a terminal buffer.
+    ///
+    NormalInTerminal,
+
+    /// `ntT`: Normal, from `t_CTRL-\_CTRL-O` in terminal mode.
+    ///
+    NormalFromTerminal,
+
+    /// `v`: Visual, charwise.
+    ///
+    Visual,
+
+    /// `vs`: Visual, charwise, from `CTRL-O` in select mode.
+    ///
+    VisualFromSelect,
+
+    /// `V`: Visual, linewise.
+    ///
+    VisualLine,
+
+    /// `Vs`: Visual, linewise, from `CTRL-O` in select mode.
+    ///
+    VisualLineFromSelect,
+
+    /// `^V`: Visual, blockwise.
+    ///
+    VisualBlock,
+
+    /// `^Vs`: Visual, blockwise, from `CTRL-O` in select mode.
+    ///
+    VisualBlockFromSelect,
+
+    /// `s`: Select, charwise.
+    ///
+    Select,
+
+    /// `S`: Select, linewise.
+    ///
+    SelectLine,
+
+    /// `^S`: Select, blockwise.
+    ///
+    SelectBlock,
+
+    /// `i`: Insert.
+    ///
+    Insert,
+
+    /// `ic`: Insert, with completion active (see `:help compl-generic`).
+    ///
+    InsertCompletion,
+
+    /// `ix`: Insert, in `i_CTRL-X` mode.
+    ///
+    InsertCtrlX,
+
+    /// `R`: Replace (see `:help R`).
+    ///
+    Replace,
+
+    /// `Rc`: Replace, with completion active.
+    ///
+    ReplaceCompletion,
+
+    /// `Rx`: Replace, in `i_CTRL-X` mode.
+    ///
+    ReplaceCtrlX,
+
+    /// `Rv`: Virtual-replace (see `:help gR`).
+    ///
+    VirtualReplace,
+
+    /// `Rvc`: Virtual-replace, with completion active.
+    ///
+    VirtualReplaceCompletion,
+
+    /// `Rvx`: Virtual-replace, in `i_CTRL-X` mode.
+    ///
+    VirtualReplaceCtrlX,
+
+    /// `c`: Command-line editing.
+    ///
+    CommandLine,
+
+    /// `cr`: Command-line editing, in overstrike mode (see `:help c_<Insert>`).
+    ///
+    CommandLineOverstrike,
+
+    /// `cv`: Vim Ex mode (see `:help gQ`).
+    ///
+    Ex,
+
+    /// `cvr`: Vim Ex mode, in overstrike mode.
+    ///
+    ExOverstrike,
+
+    /// `r`: The hit-enter prompt.
+    ///
+    HitEnterPrompt,
+
+    /// `rm`: The `-- more --` prompt.
+    ///
+    MorePrompt,
+
+    /// `r?`: A `:confirm` query of some sort.
+    ///
+    ConfirmPrompt,
+
+    /// `!`: Executing a shell command or an external command.
+    ///
+    Shell,
+
+    /// `t`: Terminal; keys go to the job.
+    ///
+    Terminal,
+}
+
+impl Mode {
+    /// Each mode, with the string that nvim uses for it.
+    ///
+    const ALL: [(Self, &'static str); 37] = [
+        (Self::Normal, "n"),
+        (Self::OperatorPending, "no"),
+        (Self::OperatorPendingCharwise, "nov"),
+        (Self::OperatorPendingLinewise, "noV"),
+        (Self::OperatorPendingBlockwise, "no\x16"),
+        (Self::NormalFromInsert, "niI"),
+        (Self::NormalFromReplace, "niR"),
+        (Self::NormalFromVirtualReplace, "niV"),
+        (Self::NormalInTerminal, "nt"),
+        (Self::NormalFromTerminal, "ntT"),
+        (Self::Visual, "v"),
+        (Self::VisualFromSelect, "vs"),
+        (Self::VisualLine, "V"),
+        (Self::VisualLineFromSelect, "Vs"),
+        (Self::VisualBlock, "\x16"),
+        (Self::VisualBlockFromSelect, "\x16s"),
+        (Self::Select, "s"),
+        (Self::SelectLine, "S"),
+        (Self::SelectBlock, "\x13"),
+        (Self::Insert, "i"),
+        (Self::InsertCompletion, "ic"),
+        (Self::InsertCtrlX, "ix"),
+        (Self::Replace, "R"),
+        (Self::ReplaceCompletion, "Rc"),
+        (Self::ReplaceCtrlX, "Rx"),
+        (Self::VirtualReplace, "Rv"),
+        (Self::VirtualReplaceCompletion, "Rvc"),
+        (Self::VirtualReplaceCtrlX, "Rvx"),
+        (Self::CommandLine, "c"),
+        (Self::CommandLineOverstrike, "cr"),
+        (Self::Ex, "cv"),
+        (Self::ExOverstrike, "cvr"),
+        (Self::HitEnterPrompt, "r"),
+        (Self::MorePrompt, "rm"),
+        (Self::ConfirmPrompt, "r?"),
+        (Self::Shell, "!"),
+        (Self::Terminal, "t"),
+    ];
+
+    /// The string that nvim uses for the mode (ex. `"niI"`). Blockwise modes use the literal
+    /// `CTRL-V` and `CTRL-S` chars, like nvim does.
+    ///
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(mode, _)| *mode == self)
+            .map_or("", |(_, s)| s)
+    }
+
+    /// Is this normal mode, or one of its sub-modes (but not operator-pending)?
+    ///
+    #[must_use]
+    pub const fn is_normal_like(self) -> bool {
+        matches!(
+            self,
+            Self::Normal
+                | Self::NormalFromInsert
+                | Self::NormalFromReplace
+                | Self::NormalFromVirtualReplace
+                | Self::NormalInTerminal
+                | Self::NormalFromTerminal
+        )
+    }
+
+    /// Is an operator pending?
+    ///
+    #[must_use]
+    pub const fn is_operator_pending(self) -> bool {
+        matches!(
+            self,
+            Self::OperatorPending
+                | Self::OperatorPendingCharwise
+                | Self::OperatorPendingLinewise
+                | Self::OperatorPendingBlockwise
+        )
+    }
+
+    /// Is this a mode where typed text goes into the buffer: insert, replace, or virtual-replace,
+    /// or one of their sub-modes?
+    ///
+    #[must_use]
+    pub const fn is_insert_like(self) -> bool {
+        matches!(
+            self,
+            Self::Insert
+                | Self::InsertCompletion
+                | Self::InsertCtrlX
+                | Self::Replace
+                | Self::ReplaceCompletion
+                | Self::ReplaceCtrlX
+                | Self::VirtualReplace
+                | Self::VirtualReplaceCompletion
+                | Self::VirtualReplaceCtrlX
+        )
+    }
+
+    /// Is there a selection: visual or select mode, of any kind?
+    ///
+    #[must_use]
+    pub const fn is_visual_like(self) -> bool {
+        self.is_visual() || self.is_select()
+    }
+
+    /// Is this visual mode (charwise, linewise, or blockwise)?
+    ///
+    #[must_use]
+    pub const fn is_visual(self) -> bool {
+        matches!(
+            self,
+            Self::Visual
+                | Self::VisualFromSelect
+                | Self::VisualLine
+                | Self::VisualLineFromSelect
+                | Self::VisualBlock
+                | Self::VisualBlockFromSelect
+        )
+    }
+
+    /// Is this select mode (charwise, linewise, or blockwise)?
+    ///
+    #[must_use]
+    pub const fn is_select(self) -> bool {
+        matches!(self, Self::Select | Self::SelectLine | Self::SelectBlock)
+    }
+
+    /// Is the command-line (or Ex mode) being edited?
+    ///
+    #[must_use]
+    pub const fn is_command_line_like(self) -> bool {
+        matches!(
+            self,
+            Self::CommandLine | Self::CommandLineOverstrike | Self::Ex | Self::ExOverstrike
+        )
+    }
+
+    /// Is nvim showing a prompt (hit-enter, more, or confirm)?
+    ///
+    #[must_use]
+    pub const fn is_prompt(self) -> bool {
+        matches!(
+            self,
+            Self::HitEnterPrompt | Self::MorePrompt | Self::ConfirmPrompt
+        )
+    }
+}
+
+impl FromStr for Mode {
+    type Err = UnknownMode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|(_, mode)| *mode == s)
+            .map(|(mode, _)| *mode)
+            .ok_or_else(|| UnknownMode(s.to_string()))
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error for when nvim reports a mode string that `Mode` doesn't know.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Unknown mode: '{}'", _0)]
+pub struct UnknownMode(String);
+
+/// The mode that nvim is in, from `nvim_get_mode()`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct CurrentMode {
+    mode: Mode,
+    blocking: bool,
+}
+
+impl CurrentMode {
+    /// Gets the current mode from nvim.
+    ///
+    /// # Errors
+    ///
+    /// * If nvim's response is missing a key, or its mode isn't known.
+    ///
+    pub fn get() -> Result<Self, CurrentModeError> {
+        Self::try_from(nvim::nvim_get_mode())
+    }
+
+    /// The current mode.
+    ///
+    #[must_use]
+    pub const fn mode(self) -> Mode {
+        self.mode
+    }
+
+    /// `true` if Nvim is waiting for input.
+    ///
+    #[must_use]
+    pub const fn blocking(self) -> bool {
+        self.blocking
+    }
+}
+
+impl TryFrom<Dictionary> for CurrentMode {
+    type Error = CurrentModeError;
+
+    fn try_from(dict: Dictionary) -> Result<Self, Self::Error> {
+        let mode = dict.get("mode").and_then(|mode| mode.try_as_string().ok());
+        let blocking = dict
+            .get("blocking")
+            .and_then(|blocking| blocking.try_as_boolean().ok());
+
+        match (mode, blocking) {
+            (Some(mode), Some(blocking)) => Ok(Self {
+                mode: mode.to_string().parse()?,
+                blocking,
+            }),
+            (None, Some(_)) => Err(CurrentModeError::Mode),
+            (Some(_), None) => Err(CurrentModeError::Blocking),
+            (None, None) => Err(CurrentModeError::ModeAndBlocking),
+        }
+    }
+}
+
+/// Error type for instantiating a `CurrentMode` from the `Dictionary` returned by neovim on
+/// related calls.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+pub enum CurrentModeError {
+    /// The `mode` key was missing, or wasn't a `String`.
+    ///
+    #[error("Underlying neovim dictionary did not have the `mode` key/value pair set")]
+    Mode,
+
+    /// The `blocking` key was missing, or wasn't a `Boolean`.
+    ///
+    #[error("Underlying neovim dictionary did not have the `blocking` key/value pair set")]
+    Blocking,
+
+    /// Both keys were missing.
+    ///
+    #[error(
+        "Underlying neovim dictionary did not have the `mode` or `blocking` key/value pairs set"
+    )]
+    ModeAndBlocking,
+
+    /// The mode string isn't one that `Mode` knows.
+    ///
+    #[error(transparent)]
+    UnknownMode(#[from] UnknownMode),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nvim_api::{NvimString, Object};
+
+    #[test]
+    fn test_from_str() {
+        for (mode, s) in Mode::ALL {
+            assert_eq!(s.parse::<Mode>().unwrap(), mode);
+            assert_eq!(mode.to_string(), s);
+        }
+
+        assert_eq!(
+            "no\x16".parse::<Mode>().unwrap(),
+            Mode::OperatorPendingBlockwise
+        );
+        assert_eq!("r?".parse::<Mode>().unwrap(), Mode::ConfirmPrompt);
+        assert!("nope".parse::<Mode>().is_err());
+        assert!("".parse::<Mode>().is_err());
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert!(Mode::NormalFromInsert.is_normal_like());
+        assert!(!Mode::NormalFromInsert.is_insert_like());
+        assert!(Mode::OperatorPendingLinewise.is_operator_pending());
+        assert!(!Mode::OperatorPending.is_normal_like());
+        assert!(Mode::VirtualReplaceCtrlX.is_insert_like());
+        assert!(Mode::InsertCompletion.is_insert_like());
+        assert!(Mode::VisualBlockFromSelect.is_visual_like());
+        assert!(Mode::SelectLine.is_visual_like());
+        assert!(!Mode::SelectLine.is_visual());
+        assert!(Mode::ExOverstrike.is_command_line_like());
+        assert!(Mode::MorePrompt.is_prompt());
+        assert!(!Mode::Terminal.is_insert_like());
+    }
+
+    #[test]
+    fn try_from_dictionary_test() {
+        let mut dict = Dictionary::default();
+        dict.set(NvimString::new_unchecked("blocking"), Object::from(false));
+
+        assert!(matches!(
+            CurrentMode::try_from(dict.clone()),
+            Err(CurrentModeError::Mode)
+        ));
+
+        dict.set(
+            NvimString::new_unchecked("mode"),
+            NvimString::new_unchecked("niI"),
+        );
+        let current_mode = CurrentMode::try_from(dict.clone()).unwrap();
+        assert_eq!(current_mode.mode(), Mode::NormalFromInsert);
+        assert!(!current_mode.blocking());
+
+        dict.set(
+            NvimString::new_unchecked("mode"),
+            NvimString::new_unchecked("zz"),
+        );
+        assert!(matches!(
+            CurrentMode::try_from(dict),
+            Err(CurrentModeError::UnknownMode(_))
+        ));
+    }
+}
@@ -16,6 +16,7 @@ macro_rules! def_settings_builder_method {
     };
 }
 
+mod background_value;
 mod clipboard_settings;
 mod color_column_value;
 mod complete_opt_settings;
@@ -28,6 +29,7 @@ mod sign_column_value;
 mod spell_lang_value;
 
 pub use self::{
+    background_value::BackgroundValue,
     clipboard_settings::ClipboardSettings,
     color_column_value::ColorColumnValue,
     complete_opt_settings::CompleteOptSettings,
@@ -488,6 +490,7 @@ macro_rules! impl_vim_option {
 }
 
 impl_vim_option!(AutoIndent, bool, "ai", "autoindent");
+impl_vim_option!(Background, string: BackgroundValue, "bg", "background");
 impl_vim_option!(BreakIndent, bool, "bri", "breakindent");
 impl_vim_option!(CmdHeight, num: u8, "ch", "cmdheight");
 impl_vim_option!(
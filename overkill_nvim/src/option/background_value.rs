@@ -0,0 +1,46 @@
+use super::NvimOptionError;
+use nvim_api::{NvimString, Object};
+use std::{borrow::Cow, convert::TryFrom};
+
+/// Represents an option value for `'background'`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundValue {
+    /// `"dark"`
+    ///
+    Dark,
+
+    /// `"light"`
+    ///
+    Light,
+}
+
+impl BackgroundValue {
+    /// The `str` nvim uses for this value.
+    ///
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+        }
+    }
+}
+
+impl From<BackgroundValue> for NvimString {
+    fn from(value: BackgroundValue) -> Self {
+        Self::new_unchecked(value.as_str())
+    }
+}
+
+impl TryFrom<NvimString> for BackgroundValue {
+    type Error = NvimOptionError;
+
+    fn try_from(value: NvimString) -> Result<Self, Self::Error> {
+        match value.to_string_lossy() {
+            Cow::Borrowed("dark") => Ok(Self::Dark),
+            Cow::Borrowed("light") => Ok(Self::Light),
+            _ => Err(NvimOptionError::UnexpectedOptionValue(Object::from(value))),
+        }
+    }
+}
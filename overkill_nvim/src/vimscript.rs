@@ -0,0 +1,224 @@
+//!
+//! This module contains functions for running Vimscript, calling Vimscript functions, and
+//! evaluating expressions. They're mainly an escape hatch for things that don't have wrappers
+//! yet.
+//!
+//! ```no_run
+//! use overkill_nvim::vimscript::{call, eval};
+//!
+//! let path: String = call("expand", ("%:p",)).unwrap();
+//! let [line, col]: [i64; 2] = call::<Vec<i64>, _>("searchpos", ("TODO", "n"))
+//!     .unwrap()
+//!     .try_into()
+//!     .unwrap();
+//! let tabstop: u8 = eval("&tabstop").unwrap();
+//! ```
+//!
+use crate::args::{IntoArgs, IntoObject};
+use nvim_api::{nvim, Object, VimException};
+use std::convert::TryFrom;
+
+/// The global variable that `exec_block()` uses for passing the caught exception back.
+///
+const EXEC_BLOCK_ERROR_VAR: &str = "overkill_exec_block_error";
+
+/// The number of lines that `exec_block()` adds before the script.
+///
+const EXEC_BLOCK_PREAMBLE_LINES: usize = 2;
+
+/// Error that happens when running Vimscript.
+///
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum VimscriptError {
+    /// The script threw an exception (or had an error). `line` is the (1-indexed) line of the
+    /// script that failed, if it's known.
+    ///
+    #[error("{}{}", line.map(|l| format!("line {l}: ")).unwrap_or_default(), exception)]
+    Exception {
+        /// The line of the script that failed.
+        ///
+        line: Option<usize>,
+
+        /// The exception.
+        ///
+        exception: VimException,
+    },
+
+    /// Can occur if an error occurred in nvim when running the script.
+    ///
+    #[error(transparent)]
+    ApiError(#[from] nvim_api::Error),
+}
+
+/// Executes `src`, like `:source`-ing a file.
+///
+/// # Errors
+///
+/// * If `src` contains a nul byte.
+/// * If the script throws an exception or has an error.
+///
+pub fn exec(src: &str) -> Result<(), VimscriptError> {
+    nvim::nvim_exec(src, false).map_err(from_api_error)?;
+
+    Ok(())
+}
+
+/// Executes `src` and returns the output it would've displayed (ex. from `:echo`).
+///
+/// # Errors
+///
+/// * If `src` contains a nul byte.
+/// * If the script throws an exception or has an error.
+///
+pub fn exec_output(src: &str) -> Result<String, VimscriptError> {
+    nvim::nvim_exec(src, true)
+        .map(Option::unwrap_or_default)
+        .map_err(from_api_error)
+}
+
+/// Like `exec()` (or `exec_output()`, if `capture_output` is `true`), but for longer scripts: if
+/// the script fails, the error includes the line of `src` that failed.
+///
+/// This works by wrapping `src` in a `:try`/`:catch`, so the first error stops the script. The
+/// line isn't known when the failure happens inside a function that `src` calls.
+///
+/// # Errors
+///
+/// * If `src` contains a nul byte.
+/// * If the script throws an exception or has an error.
+///
+pub fn exec_block(src: &str, capture_output: bool) -> Result<Option<String>, VimscriptError> {
+    let wrapped = format!(
+        "unlet! g:{EXEC_BLOCK_ERROR_VAR}\ntry\n{src}\ncatch\n\
+        let g:{EXEC_BLOCK_ERROR_VAR} = [v:exception, v:throwpoint]\nendtry"
+    );
+
+    let output = nvim::nvim_exec(&wrapped, capture_output).map_err(from_api_error)?;
+
+    let Ok(caught) = nvim::nvim_get_var(EXEC_BLOCK_ERROR_VAR) else {
+        return Ok(output);
+    };
+
+    nvim::nvim_command(&format!("unlet g:{EXEC_BLOCK_ERROR_VAR}"))?;
+
+    let caught = caught.try_into_array().map_err(nvim_api::Error::from)?;
+
+    match caught.as_slice() {
+        [exception, throwpoint] => Err(VimscriptError::Exception {
+            line: throwpoint
+                .try_as_string()
+                .ok()
+                .and_then(|t| line_from_throwpoint(&t.to_string_lossy())),
+            exception: VimException::from(
+                exception
+                    .try_as_string()
+                    .map_err(nvim_api::Error::from)?
+                    .to_string_lossy()
+                    .as_ref(),
+            ),
+        }),
+        _ => Err(VimscriptError::ApiError(nvim_api::Error::Raw(format!(
+            "Unexpected value for g:{EXEC_BLOCK_ERROR_VAR}: {caught:?}"
+        )))),
+    }
+}
+
+/// Calls Vimscript function `name` with `args`, and converts the result to `R`.
+///
+/// # Errors
+///
+/// * If `name` or any of `args` contain a nul byte.
+/// * If the function throws an exception or has an error.
+/// * If the result can't be converted to `R`.
+///
+pub fn call<R, A>(name: &str, args: A) -> Result<R, VimscriptError>
+where
+    R: TryFrom<Object>,
+    nvim_api::Error: From<R::Error>,
+    A: IntoArgs,
+{
+    let object = nvim::nvim_call_function(name, args.into_args()?).map_err(from_api_error)?;
+
+    Ok(R::try_from(object).map_err(nvim_api::Error::from)?)
+}
+
+/// Calls dictionary function `name` on `dict` with `args`, and converts the result to `R`. `dict`
+/// can be a `Dictionary`, or a string expression for one (ex. `"g:my_dict"`).
+///
+/// # Errors
+///
+/// * If `name`, `dict` or any of `args` contain a nul byte.
+/// * If the function throws an exception or has an error.
+/// * If the result can't be converted to `R`.
+///
+pub fn call_dict<R, D, A>(dict: D, name: &str, args: A) -> Result<R, VimscriptError>
+where
+    R: TryFrom<Object>,
+    nvim_api::Error: From<R::Error>,
+    D: IntoObject,
+    A: IntoArgs,
+{
+    let object = nvim::nvim_call_dict_function(dict.into_object()?, name, args.into_args()?)
+        .map_err(from_api_error)?;
+
+    Ok(R::try_from(object).map_err(nvim_api::Error::from)?)
+}
+
+/// Evaluates Vimscript expression `expr`, and converts the result to `R`.
+///
+/// # Errors
+///
+/// * If `expr` contains a nul byte.
+/// * If the expression is invalid or evaluating it fails.
+/// * If the result can't be converted to `R`.
+///
+pub fn eval<R>(expr: &str) -> Result<R, VimscriptError>
+where
+    R: TryFrom<Object>,
+    nvim_api::Error: From<R::Error>,
+{
+    let object = nvim::nvim_eval(expr).map_err(from_api_error)?;
+
+    Ok(R::try_from(object).map_err(nvim_api::Error::from)?)
+}
+
+fn from_api_error(error: nvim_api::Error) -> VimscriptError {
+    match error {
+        nvim_api::Error::VimException(exception) => VimscriptError::Exception {
+            line: None,
+            exception,
+        },
+        e => VimscriptError::ApiError(e),
+    }
+}
+
+/// Gets the line of `exec_block()`'s `src` from a `v:throwpoint` like `nvim_exec(), line 5`.
+/// Throwpoints inside functions are like `function Foo, line 2`; those lines are relative to the
+/// function, so they're ignored.
+///
+fn line_from_throwpoint(throwpoint: &str) -> Option<usize> {
+    if throwpoint.starts_with("function ") {
+        return None;
+    }
+
+    let (_, line) = throwpoint.rsplit_once(", line ")?;
+
+    line.parse::<usize>()
+        .ok()?
+        .checked_sub(EXEC_BLOCK_PREAMBLE_LINES)
+        .filter(|&line| line > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_from_throwpoint() {
+        assert_eq!(line_from_throwpoint("nvim_exec(), line 5"), Some(3));
+        assert_eq!(line_from_throwpoint("nvim_exec(), line 3"), Some(1));
+        assert_eq!(line_from_throwpoint("nvim_exec(), line 2"), None);
+        assert_eq!(line_from_throwpoint("function Meow, line 5"), None);
+        assert_eq!(line_from_throwpoint(""), None);
+    }
+}
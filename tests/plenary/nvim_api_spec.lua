@@ -6,12 +6,30 @@ ffi.cdef [[
     bool test_nvim_buf_set_var();
     bool test_nvim_get_current_buf();
     bool test_nvim_feedkeys();
+    bool test_nvim_replace_termcodes();
     bool test_nvim_get_mode();
     bool test_nvim_set_global_option();
     bool test_set_map();
     bool test_set_noremap();
     bool test_set_buf_map();
     bool test_set_buf_noremap();
+    bool test_nvim_get_current_win();
+    bool test_nvim_win_set_cursor();
+    bool test_nvim_win_set_var();
+    bool test_nvim_get_current_tabpage();
+    bool test_nvim_tabpage_set_var();
+    bool test_nvim_buf_set_lines();
+    bool test_nvim_buf_set_text();
+    bool test_nvim_create_buf();
+    bool test_nvim_create_namespace();
+    bool test_nvim_buf_set_extmark();
+    bool test_nvim_command();
+    bool test_nvim_get_hl_by_name();
+    bool test_nvim_exec();
+    bool test_nvim_call_function();
+    bool test_nvim_exec_lua();
+    bool test_callback();
+    bool test_luaref_object();
   ]]
 
 local suffix = ffi.os == "OSX" and ".dylib" or ".so"
@@ -52,6 +70,12 @@ describe(
                 assert.True(lib.test_nvim_feedkeys())
             end
         )
+        it(
+            "tests nvim_replace_termcodes()",
+            function()
+                assert.True(lib.test_nvim_replace_termcodes())
+            end
+        )
         it(
             "tests nvim_get_mode()",
             function()
@@ -88,5 +112,107 @@ describe(
                 assert.True(lib.test_set_buf_noremap())
             end
         )
+        it(
+            "tests nvim_get_current_win() and window getters",
+            function()
+                assert.True(lib.test_nvim_get_current_win())
+            end
+        )
+        it(
+            "tests nvim_win_set_cursor() and nvim_win_get_cursor()",
+            function()
+                assert.True(lib.test_nvim_win_set_cursor())
+            end
+        )
+        it(
+            "tests nvim_win_set_var() and nvim_win_get_var()",
+            function()
+                assert.True(lib.test_nvim_win_set_var())
+            end
+        )
+        it(
+            "tests nvim_get_current_tabpage() and tabpage getters",
+            function()
+                assert.True(lib.test_nvim_get_current_tabpage())
+            end
+        )
+        it(
+            "tests nvim_tabpage_set_var(), nvim_tabpage_get_var(), and nvim_tabpage_del_var()",
+            function()
+                assert.True(lib.test_nvim_tabpage_set_var())
+            end
+        )
+        it(
+            "tests nvim_buf_set_lines(), nvim_buf_get_lines(), and nvim_buf_line_count()",
+            function()
+                assert.True(lib.test_nvim_buf_set_lines())
+            end
+        )
+        it(
+            "tests nvim_buf_set_text()",
+            function()
+                assert.True(lib.test_nvim_buf_set_text())
+            end
+        )
+        it(
+            "tests nvim_create_buf() and buffer lifecycle functions",
+            function()
+                assert.True(lib.test_nvim_create_buf())
+            end
+        )
+        it(
+            "tests nvim_create_namespace() and nvim_get_namespaces()",
+            function()
+                assert.True(lib.test_nvim_create_namespace())
+            end
+        )
+        it(
+            "tests nvim_buf_set_extmark() and other extmark functions",
+            function()
+                assert.True(lib.test_nvim_buf_set_extmark())
+            end
+        )
+        it(
+            "tests nvim_command()",
+            function()
+                assert.True(lib.test_nvim_command())
+            end
+        )
+        it(
+            "tests nvim_get_hl_by_name() and nvim_get_hl_by_id()",
+            function()
+                assert.True(lib.test_nvim_get_hl_by_name())
+            end
+        )
+        it(
+            "tests nvim_exec()",
+            function()
+                assert.True(lib.test_nvim_exec())
+            end
+        )
+        it(
+            "tests nvim_call_function(), nvim_call_dict_function() and nvim_eval()",
+            function()
+                assert.True(lib.test_nvim_call_function())
+            end
+        )
+        it(
+            "tests nvim_exec_lua()",
+            function()
+                assert.True(lib.test_nvim_exec_lua())
+            end
+        )
+        it(
+            "tests calling Rust callbacks from Lua",
+            function()
+                assert.True(lib.test_callback())
+            end
+        )
+        it(
+            "tests the ownership of LuaRef Objects",
+            function()
+                assert.True(lib.test_luaref_object())
+            end
+        )
     end
 )
@@ -7,6 +7,18 @@ ffi.cdef [[
     bool test_overkill_set_add_assign_global_nullable_string_char_flags_option();
     bool test_overkill_set_sub_assign_global_nullable_string_char_flags_option();
 
+    bool test_buffer();
+    bool test_buffer_name();
+    bool test_highlight_set();
+    bool test_highlight_get();
+    bool test_colorscheme_apply();
+
+    bool test_exec_block();
+    bool test_exec_output_silent();
+    bool test_call();
+
+    bool test_lua_call();
+
     bool test_map();
     bool test_map_normal();
     bool test_map_visual_select();
@@ -18,6 +30,17 @@ ffi.cdef [[
     bool test_map_language_mapping();
     bool test_map_command_line();
     bool test_map_terminal_job();
+    bool test_map_fn();
+    bool test_unmap();
+    bool test_list_merged();
+    bool test_desc_and_registry();
+    bool test_map_in();
+
+    bool test_abbreviate();
+
+    bool test_feedkeys();
+
+    bool test_current_mode();
 ]]
 
 local suffix = ffi.os == "OSX" and ".dylib" or ".so"
@@ -56,6 +79,60 @@ describe(
                 assert.True(lib.test_overkill_set_sub_assign_global_nullable_string_char_flags_option())
             end
         )
+        it(
+            "tests Buffer",
+            function()
+                assert.True(lib.test_buffer())
+            end
+        )
+        it(
+            "tests Buffer::name() for unnamed and named buffers",
+            function()
+                assert.True(lib.test_buffer_name())
+            end
+        )
+        it(
+            "tests Highlight::set()",
+            function()
+                assert.True(lib.test_highlight_set())
+            end
+        )
+        it(
+            "tests Highlight::get()",
+            function()
+                assert.True(lib.test_highlight_get())
+            end
+        )
+        it(
+            "tests Colorscheme::apply()",
+            function()
+                assert.True(lib.test_colorscheme_apply())
+            end
+        )
+        it(
+            "tests vimscript::exec_block()",
+            function()
+                assert.True(lib.test_exec_block())
+            end
+        )
+        it(
+            "tests vimscript::exec_output() for a script that outputs nothing",
+            function()
+                assert.True(lib.test_exec_output_silent())
+            end
+        )
+        it(
+            "tests vimscript::call() and vimscript::eval()",
+            function()
+                assert.True(lib.test_call())
+            end
+        )
+        it(
+            "tests lua::call() and lua::exec()",
+            function()
+                assert.True(lib.test_lua_call())
+            end
+        )
         it(
             "tests Mapper::map() with default mode",
             function()
@@ -123,5 +200,53 @@ describe(
                 assert.True(lib.test_map_terminal_job())
             end
         )
+        it(
+            "tests Mapper::map_fn() and Mapper::buf_map_fn()",
+            function()
+                assert.True(lib.test_map_fn())
+            end
+        )
+        it(
+            "tests Mapper::unmap(), buf_unmap(), clear() and buf_clear()",
+            function()
+                assert.True(lib.test_unmap())
+            end
+        )
+        it(
+            "tests Mapper::list_for_buffer() and Mapper::list_merged()",
+            function()
+                assert.True(lib.test_list_merged())
+            end
+        )
+        it(
+            "tests mapping descriptions and the mapping registry",
+            function()
+                assert.True(lib.test_desc_and_registry())
+            end
+        )
+        it(
+            "tests Mapper::map_in()",
+            function()
+                assert.True(lib.test_map_in())
+            end
+        )
+        it(
+            "tests Abbreviator",
+            function()
+                assert.True(lib.test_abbreviate())
+            end
+        )
+        it(
+            "tests feedkeys() and feed_sequence()",
+            function()
+                assert.True(lib.test_feedkeys())
+            end
+        )
+        it(
+            "tests CurrentMode::get()",
+            function()
+                assert.True(lib.test_current_mode())
+            end
+        )
     end
 )